@@ -27,7 +27,17 @@ export function fragmentReplace(f,n)
 	f.insertBefore(b, f.firstChild);
 }
 
+export function firstNode(n)
+{
+	let d = fragmentDecorators.get(n);
+	return d ? d[0] : n;
+}
+
+export function activeElement() { return document.activeElement; }
+export function restoreFocus(n) { if (n && n !== document.activeElement && n.isConnected) n.focus(); }
+
 export function setChecked(n,v) { if (n.checked !== v) n.checked = v; }
+export function setValue(n,v) { if (n.value !== String(v)) n.value = v; }
 
 export function addClass(n,v) { n.classList.add(v); }
 export function removeClass(n,v) { n.classList.remove(v); }
@@ -35,6 +45,11 @@ export function replaceClass(n,o,v) { n.classList.replace(o,v); }
 export function toggleClass(n,c,v) { n.classList.toggle(c,v); }
 
 export function makeEventHandler(c,f) { return (e) => wasmBindings.koboldCallback(e,c,f); }
+export function preventDefault(h) { return (e) => { e.preventDefault(); h(e); }; }
+let keyWarned = false;
+export function warnUnusedKey() { if (!keyWarned) { keyWarned = true; console.warn(
+"A view with a `key` was rendered outside of a `for keyed` list, the key is ignored."
+) } }
 export function checkEventHandler() { if (typeof wasmBindings !== "object") console.error(
 `Missing \`wasmBindings\` in global scope.
 As of Kobold v0.10 and Trunk v0.17.16 you no longer need to export bindings manually, \
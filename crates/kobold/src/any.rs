@@ -0,0 +1,162 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Type-erased views, see [`AnyView`]
+
+use std::any::Any;
+
+use wasm_bindgen::JsValue;
+use web_sys::Node;
+
+use crate::dom::Mountable;
+use crate::internal::{In, Out};
+use crate::View;
+
+/// A [`View`] with its type erased, making it possible to store views of different
+/// types together, e.g. in a `Vec` of widgets that isn't known at compile time:
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::any::AnyView;
+///
+/// #[component]
+/// fn clock(time: &str) -> impl View + '_ {
+///     view! { <p.clock>{ time }</p> }
+/// }
+///
+/// #[component]
+/// fn weather(celsius: i32) -> impl View {
+///     view! { <p.weather>{ celsius }"°C"</p> }
+/// }
+///
+/// fn dashboard(show_weather: bool) -> impl View {
+///     let mut widgets = vec![AnyView::new(view! { <!clock time="12:00"> })];
+///
+///     if show_weather {
+///         widgets.push(AnyView::new(view! { <!weather celsius={21}> }));
+///     }
+///
+///     view! {
+///         <div.dashboard>{ for widgets }</div>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// # Performance
+///
+/// Unlike most views `AnyView` always allocates: the view itself is boxed when the
+/// `AnyView` is created, and its product is boxed when it's built. Updates go through
+/// dynamic dispatch, and if the view is of a different type than the one that was
+/// previously built, the old product is dropped and a new one is built in its place.
+///
+/// Prefer the [`BranchN` enums](crate::branching) or [`auto_branch`](crate::component#componentauto_branch)
+/// whenever the set of view types is known at compile time.
+pub struct AnyView<'a>(Box<dyn DynView + 'a>);
+
+impl<'a> AnyView<'a> {
+    /// Erase the type of a `view`.
+    pub fn new<V>(view: V) -> Self
+    where
+        V: View + 'a,
+    {
+        AnyView(Box::new(view))
+    }
+}
+
+/// Product of an [`AnyView`].
+pub struct AnyProduct(Box<dyn DynProduct>);
+
+impl View for AnyView<'_> {
+    type Product = AnyProduct;
+
+    fn build(self, p: In<AnyProduct>) -> Out<AnyProduct> {
+        p.put(AnyProduct(self.0.build_dyn()))
+    }
+
+    fn update(self, p: &mut AnyProduct) {
+        self.0.update_dyn(&mut p.0);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.0.render_html_dyn(out);
+    }
+}
+
+impl Mountable for AnyProduct {
+    type Js = Node;
+
+    fn js(&self) -> &JsValue {
+        self.0.js_dyn()
+    }
+
+    fn unmount(&self) {
+        self.0.unmount_dyn();
+    }
+
+    fn replace_with(&self, new: &JsValue) {
+        self.0.replace_with_dyn(new);
+    }
+}
+
+trait DynView {
+    fn build_dyn(self: Box<Self>) -> Box<dyn DynProduct>;
+
+    fn update_dyn(self: Box<Self>, p: &mut Box<dyn DynProduct>);
+
+    #[cfg(feature = "ssr")]
+    fn render_html_dyn(self: Box<Self>, out: &mut String);
+}
+
+impl<V: View> DynView for V {
+    fn build_dyn(self: Box<Self>) -> Box<dyn DynProduct> {
+        In::boxed(move |p| View::build(*self, p))
+    }
+
+    fn update_dyn(self: Box<Self>, p: &mut Box<dyn DynProduct>) {
+        if let Some(p) = (**p).as_any_mut().downcast_mut::<V::Product>() {
+            return View::update(*self, p);
+        }
+
+        let new = self.build_dyn();
+
+        p.replace_with_dyn(new.js_dyn());
+
+        *p = new;
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html_dyn(self: Box<Self>, out: &mut String) {
+        View::render_html(*self, out);
+    }
+}
+
+trait DynProduct {
+    fn js_dyn(&self) -> &JsValue;
+
+    fn unmount_dyn(&self);
+
+    fn replace_with_dyn(&self, new: &JsValue);
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<P: Mountable> DynProduct for P {
+    fn js_dyn(&self) -> &JsValue {
+        self.js()
+    }
+
+    fn unmount_dyn(&self) {
+        self.unmount();
+    }
+
+    fn replace_with_dyn(&self, new: &JsValue) {
+        self.replace_with(new);
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -33,22 +33,43 @@ impl Property<&str> for &AttributeName {
     fn set(self, this: &Node, value: &str) {
         internal::obj(this).set_attr(self, value);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: &str, out: &mut String) {
+        crate::ssr::attribute(self, value, out);
+    }
 }
 
 impl Property<f64> for &AttributeName {
     fn set(self, this: &Node, value: f64) {
         internal::obj(this).set_attr_num(self, value)
     }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: f64, out: &mut String) {
+        crate::ssr::attribute(self, value, out);
+    }
 }
 
 impl Property<bool> for &AttributeName {
     fn set(self, this: &Node, value: bool) {
         internal::obj(this).set_attr_bool(self, value);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: bool, out: &mut String) {
+        crate::ssr::attribute(self, value, out);
+    }
+}
+
+impl Removable for &AttributeName {
+    fn remove(self, this: &Node) {
+        internal::obj(this).remove_attr(self);
+    }
 }
 
 macro_rules! attribute {
-    ($(#[doc = $doc:literal] $name:ident [ $($util:ident: $abi:ty),* ])*) => {
+    ($(#[doc = $doc:literal] $name:ident ($html:literal) [ $($util:ident: $abi:ty),* ])*) => {
         $(
             #[doc = $doc]
             pub struct $name;
@@ -58,8 +79,19 @@ macro_rules! attribute {
                     fn set(self, this: &Node, value: $abi) {
                         internal::obj(this).$util(value);
                     }
+
+                    #[cfg(feature = "ssr")]
+                    fn render(self, value: $abi, out: &mut String) {
+                        crate::ssr::attribute($html, value, out);
+                    }
                 }
             )*
+
+            impl Removable for $name {
+                fn remove(self, this: &Node) {
+                    internal::obj(this).remove_attr($html);
+                }
+            }
         )*
     }
 }
@@ -71,24 +103,101 @@ impl Property<bool> for Checked {
     fn set(self, this: &Node, value: bool) {
         internal::checked(this, value);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: bool, out: &mut String) {
+        if value {
+            out.push_str(" checked");
+        }
+    }
+}
+
+impl Removable for Checked {
+    fn remove(self, this: &Node) {
+        internal::checked(this, false);
+    }
+}
+
+/// The `innerHTML` attribute: <https://developer.mozilla.org/en-US/docs/Web/API/Element/innerHTML>
+pub struct InnerHtml;
+
+impl Property<&str> for InnerHtml {
+    fn set(self, this: &Node, value: &str) {
+        internal::obj(this).inner_html(value);
+    }
+
+    /// Renders `value` verbatim as the content of the element.
+    #[cfg(feature = "ssr")]
+    fn render(self, value: &str, out: &mut String) {
+        out.push_str(value);
+    }
 }
 
 /// The `Element.classList` property: <https://developer.mozilla.org/en-US/docs/Web/API/Element/classList>
 pub struct Class;
 
+/// Adds a class to the `Element.classList` property without ever removing it,
+/// used for `{ static ... }` class names.
+pub struct AddClass;
+
+impl Property<&str> for AddClass {
+    fn set(self, this: &Node, value: &str) {
+        debug_test_class(value);
+        set_class(this, value);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: &str, out: &mut String) {
+        crate::ssr::class(value, out);
+    }
+}
+
 attribute!(
     /// The `className` attribute: <https://developer.mozilla.org/en-US/docs/Web/API/Element/className>
-    ClassName [class_name: &str]
-    /// The `innerHTML` attribute: <https://developer.mozilla.org/en-US/docs/Web/API/Element/innerHTML>
-    InnerHtml [inner_html: &str]
+    ClassName("class") [class_name: &str]
     /// The `style` attribute: <https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/style>
-    Style [style: &str]
+    Style("style") [style: &str]
     /// The `href` attribute: <https://developer.mozilla.org/en-US/docs/Web/API/HTMLAnchorElement/href>
-    Href [href: &str]
-    /// The `value` attribute: <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#value>
-    Value [value: &str, value_num: f64]
+    Href("href") [href: &str]
 );
 
+/// The `value` attribute: <https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#value>
+///
+/// The value is only written to the element if it differs from the current value of the
+/// element. When an input is kept in sync with state on every keystroke the rendered value
+/// already matches what the user typed, so the cursor and selection are left alone.
+pub struct Value;
+
+impl Property<&str> for Value {
+    fn set(self, this: &Node, value: &str) {
+        internal::value(this, value);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: &str, out: &mut String) {
+        crate::ssr::attribute("value", value, out);
+    }
+}
+
+impl Property<f64> for Value {
+    fn set(self, this: &Node, value: f64) {
+        internal::value_num(this, value);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: f64, out: &mut String) {
+        crate::ssr::attribute("value", value, out);
+    }
+}
+
+/// Clears both the attribute and the `value` property, the latter being what inputs display.
+impl Removable for Value {
+    fn remove(self, this: &Node) {
+        internal::obj(this).remove_attr("value");
+        internal::value(this, "");
+    }
+}
+
 pub trait Attribute<P> {
     type Product: 'static;
 
@@ -97,6 +206,65 @@ pub trait Attribute<P> {
     fn build_in(self, prop: P, node: &Node) -> Self::Product;
 
     fn update_in(self, prop: P, node: &Node, memo: &mut Self::Product);
+
+    /// Render this attribute as HTML, see [`ssr`](crate::ssr).
+    #[cfg(feature = "ssr")]
+    fn render_html(self, prop: P, out: &mut String);
+}
+
+/// A property that can be removed from a DOM `Node`, used to unset `None` values
+/// of [`Option`] attributes.
+pub trait Removable {
+    fn remove(self, this: &Node);
+}
+
+/// `Some` value sets the attribute, while `None` removes it from the element.
+///
+/// ```
+/// use kobold::prelude::*;
+///
+/// #[component]
+/// fn submit(busy: bool) -> impl View {
+///     view! {
+///         // The `disabled` attribute is only present while `busy`
+///         <button disabled={ busy.then_some(true) }>"Submit"</button>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+impl<T, P> Attribute<P> for Option<T>
+where
+    T: Attribute<P>,
+    P: Removable,
+{
+    type Product = Option<T::Product>;
+
+    fn build(self) -> Self::Product {
+        self.map(T::build)
+    }
+
+    fn build_in(self, prop: P, node: &Node) -> Self::Product {
+        self.map(|value| value.build_in(prop, node))
+    }
+
+    fn update_in(self, prop: P, node: &Node, memo: &mut Self::Product) {
+        match (self, memo) {
+            (Some(value), Some(memo)) => value.update_in(prop, node, memo),
+            (Some(value), memo) => *memo = Some(value.build_in(prop, node)),
+            (None, memo @ Some(_)) => {
+                prop.remove(node);
+                *memo = None;
+            }
+            (None, None) => (),
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, prop: P, out: &mut String) {
+        if let Some(value) = self {
+            value.render_html(prop, out);
+        }
+    }
 }
 
 impl<P> Attribute<P> for String
@@ -120,6 +288,11 @@ where
             *prod = self;
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, prop: P, out: &mut String) {
+        self.as_str().render_prop(prop, out);
+    }
 }
 
 impl<P> Attribute<P> for bool
@@ -139,6 +312,11 @@ where
     fn update_in(self, prop: P, node: &Node, _: &mut ()) {
         self.set_prop(prop, node);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, prop: P, out: &mut String) {
+        self.render_prop(prop, out);
+    }
 }
 
 macro_rules! impl_attribute_view {
@@ -164,6 +342,11 @@ macro_rules! impl_attribute_view {
                         self.set_prop(prop, node);
                     }
                 }
+
+                #[cfg(feature = "ssr")]
+                fn render_html(self, prop: P, out: &mut String) {
+                    self.render_prop(prop, out);
+                }
             }
         )*
     };
@@ -172,6 +355,95 @@ macro_rules! impl_attribute_view {
 impl_attribute_view!(&str, &String, &Ref<str>, &VString);
 impl_attribute_view!(u8, u16, u32, u64, u128, usize, isize, i8, i16, i32, i64, i128, f32, f64);
 
+/// A dynamic collection of attributes set with `setAttribute`, used by the `attrs` attribute
+/// in the [`view!`](crate::view) macro.
+///
+/// The collection can be any iterator of `(name, value)` pairs, and is diffed against the
+/// previous render: new attributes are set, changed values are updated and attributes
+/// missing from the collection are removed from the element.
+///
+/// ```
+/// use kobold::prelude::*;
+///
+/// #[component]
+/// fn cell(data: &[(String, String)]) -> impl View + '_ {
+///     view! {
+///         <td attrs={ data.iter().map(|(name, value)| (format!("data-{name}"), value)) }>
+///             "Cell"
+///         </td>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub struct Attributes;
+
+impl<T, K, V> Attribute<Attributes> for T
+where
+    T: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    type Product = Vec<(String, String)>;
+
+    fn build(self) -> Self::Product {
+        self.into_iter()
+            .map(|(name, value)| (name.as_ref().into(), value.as_ref().into()))
+            .collect()
+    }
+
+    fn build_in(self, _: Attributes, node: &Node) -> Self::Product {
+        let attrs = self.build();
+
+        for (name, value) in attrs.iter() {
+            internal::obj(node).set_attr(name, value);
+        }
+
+        attrs
+    }
+
+    fn update_in(self, _: Attributes, node: &Node, memo: &mut Self::Product) {
+        // Attributes matched so far are moved to the front of `memo`,
+        // anything left past `matched` is no longer present.
+        let mut matched = 0;
+
+        for (name, value) in self {
+            let (name, value) = (name.as_ref(), value.as_ref());
+
+            match memo[matched..].iter().position(|(n, _)| n == name) {
+                Some(idx) => {
+                    memo.swap(matched, matched + idx);
+
+                    let old = &mut memo[matched].1;
+
+                    if old != value {
+                        internal::obj(node).set_attr(name, value);
+
+                        old.clear();
+                        old.push_str(value);
+                    }
+                }
+                None => {
+                    internal::obj(node).set_attr(name, value);
+                    memo.insert(matched, (name.into(), value.into()));
+                }
+            }
+
+            matched += 1;
+        }
+
+        for (name, _) in memo.drain(matched..) {
+            internal::obj(node).remove_attr(&name);
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: Attributes, out: &mut String) {
+        for (name, value) in self {
+            crate::ssr::attribute(name.as_ref(), value.as_ref(), out);
+        }
+    }
+}
+
 #[inline]
 fn debug_test_class(class: &str) {
     debug_assert!(
@@ -218,6 +490,11 @@ where
             old.push_str(self.as_ref());
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: Class, out: &mut String) {
+        crate::ssr::class(self.as_ref(), out);
+    }
 }
 
 impl Attribute<Class> for String {
@@ -238,12 +515,19 @@ impl Attribute<Class> for String {
             *old = self;
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: Class, out: &mut String) {
+        crate::ssr::class(&self, out);
+    }
 }
 
 #[derive(Clone, Copy)]
 pub struct StaticClass<T> {
     toggle: T,
     on: bool,
+    #[cfg(feature = "ssr")]
+    class: &'static str,
 }
 
 impl<T> StaticClass<T> {
@@ -251,7 +535,19 @@ impl<T> StaticClass<T> {
     where
         T: Fn(&Node, bool),
     {
-        StaticClass { toggle, on }
+        StaticClass {
+            toggle,
+            on,
+            #[cfg(feature = "ssr")]
+            class: "",
+        }
+    }
+
+    /// Set the class name used when rendering HTML, see [`ssr`](crate::ssr).
+    #[cfg(feature = "ssr")]
+    pub const fn with_class(mut self, class: &'static str) -> Self {
+        self.class = class;
+        self
     }
 }
 
@@ -276,6 +572,13 @@ where
             *memo = self.on;
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: Class, out: &mut String) {
+        if self.on {
+            crate::ssr::class(self.class, out);
+        }
+    }
 }
 
 impl<T> Attribute<ClassName> for StaticClass<T>
@@ -299,6 +602,13 @@ where
             *memo = self.on;
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: ClassName, out: &mut String) {
+        if self.on {
+            crate::ssr::attribute("class", self.class, out);
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -332,6 +642,13 @@ impl Attribute<Class> for OptionalClass {
             *memo = self.on;
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: Class, out: &mut String) {
+        if self.on {
+            crate::ssr::class(self.class, out);
+        }
+    }
 }
 
 impl Attribute<ClassName> for OptionalClass {
@@ -355,4 +672,11 @@ impl Attribute<ClassName> for OptionalClass {
             *memo = self.on;
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: ClassName, out: &mut String) {
+        if self.on {
+            crate::ssr::attribute("class", self.class, out);
+        }
+    }
 }
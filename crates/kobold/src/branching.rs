@@ -57,8 +57,6 @@
 //!
 //! ```
 //! # use kobold::prelude::*;
-//! use kobold::branching::Branch2;
-//!
 //! #[component]
 //! fn conditional(illuminatus: bool) -> impl View {
 //!     if illuminatus {
@@ -76,7 +74,25 @@
 //!
 //! This is in fact all that the [`auto_branch`](crate::component#componentauto_branch) flag does for you automatically.
 //!
-//! For simple optional renders you can always use the standard library [`Option`]:
+//! Only [`Branch2`] and [`Branch3`] are in the [`prelude`](crate::prelude), the [`branch!`](crate::branch)
+//! macro picks the enum of the right arity for you. Arms that render nothing can use [`Empty`]:
+//!
+//! ```
+//! # use kobold::prelude::*;
+//! #[component]
+//! fn status(code: u16) -> impl View {
+//!     match code {
+//!         200 => branch!(A of 4, view! { <p>"OK"</p> }),
+//!         404 => branch!(B of 4, view! { <p.warning>"Not Found"</p> }),
+//!         500.. => branch!(C of 4, view! { <p.error>"Server Error: "{ code }</p> }),
+//!         _ => branch!(D of 4, Empty),
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! For simple optional renders you can always use the standard library [`Option`], and
+//! [`Result`] renders either of its views:
 //!
 //! ```
 //! # use kobold::prelude::*;
@@ -92,6 +108,16 @@
 //! }
 //! # fn main() {}
 //! ```
+//!
+//! ## Compile-time conditions
+//!
+//! Subtrees behind a constant condition, such as a `cfg!` flag, can use
+//! [`if const`](crate::keywords::if_const) instead, which doesn't branch at runtime at all.
+//!
+//! ## Placeholders
+//!
+//! To show a skeleton while some data is loading use [`placeholder_while`], which
+//! keeps both the skeleton and the content around instead of rebuilding them on every switch.
 
 use std::mem::MaybeUninit;
 use std::pin::Pin;
@@ -100,10 +126,10 @@ use wasm_bindgen::JsValue;
 use web_sys::Node;
 
 use crate::dom::Anchor;
-use crate::internal::{empty_node, In, Out};
+use crate::internal::{empty_node, fragment, In, Out};
 use crate::{Mountable, View};
 
-macro_rules! branch {
+macro_rules! impl_branch {
     ($name:ident < $($var:ident),* >) => {
         #[repr(C)]
         pub enum $name<$($var),*> {
@@ -156,6 +182,15 @@ macro_rules! branch {
                     }
                 }
             }
+
+            #[cfg(feature = "ssr")]
+            fn render_html(self, out: &mut String) {
+                match self {
+                    $(
+                        $name::$var(html) => html.render_html(out),
+                    )*
+                }
+            }
         }
 
         impl<$($var),*> Mountable for $name<$($var),*>
@@ -193,17 +228,50 @@ macro_rules! branch {
     };
 }
 
-branch!(Branch2<A, B>);
-branch!(Branch3<A, B, C>);
-branch!(Branch4<A, B, C, D>);
-branch!(Branch5<A, B, C, D, E>);
-branch!(Branch6<A, B, C, D, E, F>);
-branch!(Branch7<A, B, C, D, E, F, G>);
-branch!(Branch8<A, B, C, D, E, F, G, H>);
-branch!(Branch9<A, B, C, D, E, F, G, H, I>);
+impl_branch!(Branch2<A, B>);
+impl_branch!(Branch3<A, B, C>);
+impl_branch!(Branch4<A, B, C, D>);
+impl_branch!(Branch5<A, B, C, D, E>);
+impl_branch!(Branch6<A, B, C, D, E, F>);
+impl_branch!(Branch7<A, B, C, D, E, F, G>);
+impl_branch!(Branch8<A, B, C, D, E, F, G, H>);
+impl_branch!(Branch9<A, B, C, D, E, F, G, H, I>);
+
+/// Wrap a view in a variant of the [`BranchN` enum](self#enums) with `N` branches,
+/// `branch!(C of 5, view)` is equivalent to `Branch5::C(view)`.
+///
+/// See the [`branching` module documentation](self) for details.
+#[macro_export]
+macro_rules! branch {
+    ($var:ident of 2, $view:expr) => {
+        $crate::branching::Branch2::$var($view)
+    };
+    ($var:ident of 3, $view:expr) => {
+        $crate::branching::Branch3::$var($view)
+    };
+    ($var:ident of 4, $view:expr) => {
+        $crate::branching::Branch4::$var($view)
+    };
+    ($var:ident of 5, $view:expr) => {
+        $crate::branching::Branch5::$var($view)
+    };
+    ($var:ident of 6, $view:expr) => {
+        $crate::branching::Branch6::$var($view)
+    };
+    ($var:ident of 7, $view:expr) => {
+        $crate::branching::Branch7::$var($view)
+    };
+    ($var:ident of 8, $view:expr) => {
+        $crate::branching::Branch8::$var($view)
+    };
+    ($var:ident of 9, $view:expr) => {
+        $crate::branching::Branch9::$var($view)
+    };
+}
 
 pub struct EmptyNode(Node);
 
+/// A [`View`] that renders nothing, useful for branches with nothing to show.
 pub struct Empty;
 
 impl Anchor for EmptyNode {
@@ -223,6 +291,231 @@ impl View for Empty {
     }
 
     fn update(self, _: &mut EmptyNode) {}
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: &mut String) {}
+}
+
+/// A [`View`] that is compiled out when `B` is `false`, created by [`if const`](crate::keywords::if_const).
+#[repr(transparent)]
+pub struct IfConst<const B: bool, F>(pub(crate) F);
+
+/// Zero-sized product of an [`IfConst`] with a `false` condition.
+pub struct Omitted;
+
+impl<F, V> View for IfConst<true, F>
+where
+    F: FnOnce() -> V,
+    V: View,
+{
+    type Product = V::Product;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        (self.0)().build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        (self.0)().update(p)
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (self.0)().render_html(out)
+    }
+}
+
+impl<F> View for IfConst<false, F> {
+    type Product = Omitted;
+
+    fn build(self, p: In<Omitted>) -> Out<Omitted> {
+        p.put(Omitted)
+    }
+
+    fn update(self, _: &mut Omitted) {}
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: &mut String) {}
+}
+
+thread_local! {
+    // Appending an empty fragment doesn't change the DOM and leaves the fragment empty,
+    // so a single one can stand in for every omitted view.
+    static OMITTED: &'static Node = Box::leak(Box::new(fragment()));
+}
+
+impl Mountable for Omitted {
+    type Js = Node;
+
+    fn js(&self) -> &JsValue {
+        OMITTED.with(|node| *node)
+    }
+
+    fn unmount(&self) {}
+
+    fn replace_with(&self, _: &JsValue) {
+        debug_assert!(
+            false,
+            "`if const` with a `false` condition can't be replaced, it needs to be inside an element"
+        );
+    }
+}
+
+/// Render `skeleton` while `loading` is `true`, and the view returned by `content` once it's `false`.
+///
+/// Unlike switching between two [`Branch2`] variants, both products are kept around once built,
+/// so toggling `loading` back and forth swaps the existing DOM nodes without rebuilding them.
+/// The `content` closure is only called when the content is shown.
+///
+/// ```
+/// use kobold::branching::placeholder_while;
+/// use kobold::prelude::*;
+///
+/// #[component]
+/// fn profile(name: Option<&str>) -> impl View + '_ {
+///     placeholder_while(
+///         name.is_none(),
+///         view! { <p.skeleton>"Loading..."</p> },
+///         move || view! { <p>"Hello, "{ name.unwrap_or_default() }</p> },
+///     )
+/// }
+/// # fn main() {}
+/// ```
+pub const fn placeholder_while<S, F>(
+    loading: bool,
+    skeleton: S,
+    content: F,
+) -> PlaceholderWhile<S, F> {
+    PlaceholderWhile {
+        loading,
+        skeleton,
+        content,
+    }
+}
+
+/// A [`View`] created by [`placeholder_while`].
+pub struct PlaceholderWhile<S, F> {
+    loading: bool,
+    skeleton: S,
+    content: F,
+}
+
+/// Product of [`PlaceholderWhile`], holding on to both the skeleton and the content once built.
+pub struct PlaceholderProduct<S, C> {
+    loading: bool,
+    skeleton: Option<Box<S>>,
+    content: Option<Box<C>>,
+}
+
+impl<S, C> PlaceholderProduct<S, C>
+where
+    S: Mountable,
+    C: Mountable,
+{
+    fn show_skeleton<V>(&mut self, view: V)
+    where
+        V: View<Product = S>,
+    {
+        match &mut self.skeleton {
+            Some(skeleton) => view.update(skeleton),
+            None => self.skeleton = Some(In::boxed(|p| view.build(p))),
+        }
+
+        if let (false, Some(skeleton), Some(content)) =
+            (self.loading, &self.skeleton, &self.content)
+        {
+            content.replace_with(skeleton.js());
+        }
+        self.loading = true;
+    }
+
+    fn show_content<V>(&mut self, view: V)
+    where
+        V: View<Product = C>,
+    {
+        match &mut self.content {
+            Some(content) => view.update(content),
+            None => self.content = Some(In::boxed(|p| view.build(p))),
+        }
+
+        if let (true, Some(skeleton), Some(content)) = (self.loading, &self.skeleton, &self.content)
+        {
+            skeleton.replace_with(content.js());
+        }
+        self.loading = false;
+    }
+}
+
+impl<S, F, V> View for PlaceholderWhile<S, F>
+where
+    S: View,
+    F: FnOnce() -> V,
+    V: View,
+{
+    type Product = PlaceholderProduct<S::Product, V::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        let mut p = p.put(PlaceholderProduct {
+            loading: self.loading,
+            skeleton: None,
+            content: None,
+        });
+
+        if self.loading {
+            p.show_skeleton(self.skeleton);
+        } else {
+            p.show_content((self.content)());
+        }
+        p
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        if self.loading {
+            p.show_skeleton(self.skeleton);
+        } else {
+            p.show_content((self.content)());
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        if self.loading {
+            self.skeleton.render_html(out);
+        } else {
+            (self.content)().render_html(out);
+        }
+    }
+}
+
+impl<S, C> Mountable for PlaceholderProduct<S, C>
+where
+    S: Mountable,
+    C: Mountable,
+{
+    type Js = Node;
+
+    fn js(&self) -> &JsValue {
+        match (self.loading, &self.skeleton, &self.content) {
+            (true, Some(skeleton), _) => skeleton.js(),
+            (false, _, Some(content)) => content.js(),
+            _ => unsafe { std::hint::unreachable_unchecked() },
+        }
+    }
+
+    fn unmount(&self) {
+        match (self.loading, &self.skeleton, &self.content) {
+            (true, Some(skeleton), _) => skeleton.unmount(),
+            (false, _, Some(content)) => content.unmount(),
+            _ => unsafe { std::hint::unreachable_unchecked() },
+        }
+    }
+
+    fn replace_with(&self, new: &JsValue) {
+        match (self.loading, &self.skeleton, &self.content) {
+            (true, Some(skeleton), _) => skeleton.replace_with(new),
+            (false, _, Some(content)) => content.replace_with(new),
+            _ => unsafe { std::hint::unreachable_unchecked() },
+        }
+    }
 }
 
 impl<T: View> View for Option<T> {
@@ -262,4 +555,56 @@ impl<T: View> View for Option<T> {
             }
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        if let Some(html) = self {
+            html.render_html(out);
+        }
+    }
+}
+
+/// Render either of the views, with the same product as a [`Branch2`]:
+///
+/// ```
+/// # use kobold::prelude::*;
+/// fn load(id: u32) -> Result<String, String> {
+///     match id {
+///         0 => Err("Not found".into()),
+///         _ => Ok(format!("Item #{id}")),
+///     }
+/// }
+///
+/// #[component]
+/// fn item(id: u32) -> impl View {
+///     load(id)
+///         .map(|name| view! { <p>{ name }</p> })
+///         .map_err(|err| view! { <p.error>{ err }</p> })
+/// }
+/// # fn main() {}
+/// ```
+impl<A: View, B: View> View for Result<A, B> {
+    type Product = Branch2<A::Product, B::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        Branch2::from(self).build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        Branch2::from(self).update(p)
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        Branch2::from(self).render_html(out)
+    }
+}
+
+impl<A, B> From<Result<A, B>> for Branch2<A, B> {
+    fn from(result: Result<A, B>) -> Self {
+        match result {
+            Ok(a) => Branch2::A(a),
+            Err(b) => Branch2::B(b),
+        }
+    }
 }
@@ -4,18 +4,25 @@
 
 //! Utilities for diffing values in render functions.
 
+use std::ffi::{OsStr, OsString};
+use std::num::Wrapping;
 use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use web_sys::Node;
 
 use crate::attribute::Attribute;
+use crate::branching::{Branch2, EmptyNode};
 use crate::dom::{Anchor, TextContent};
 use crate::internal::{In, Out};
 use crate::value::{IntoText, Value};
 use crate::{init, Mountable, View};
 
+mod ver;
 mod vstring;
 
+pub use ver::{Ver, VerVec};
 pub use vstring::VString;
 
 /// Create a wrapper around a `view` that will prevent updates to it, unless
@@ -86,6 +93,47 @@ where
     Invar(render)
 }
 
+/// Create a wrapper around a `view` that isn't built until `visible` is `true`
+/// for the first time, useful for tabs or accordion panels.
+///
+/// Until then the view is just an empty marker in the DOM. Once revealed it stays
+/// mounted even if `visible` changes back to `false`, it's only updated while visible.
+/// This differs from rendering an [`Option`], which removes the view from the DOM on
+/// `None` and has to build it again from scratch on `Some`.
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::diff::lazy;
+///
+/// #[component]
+/// fn tabs() -> impl View {
+///     stateful(0_usize, |tab| {
+///         let current = tab.get();
+///
+///         view! {
+///             <button onclick={do *tab = 0}>"General"</button>
+///             <button onclick={do *tab = 1}>"Advanced"</button>
+///
+///             <div hidden={current != 0}>
+///                 { lazy(current == 0, || view! { <p>"General settings"</p> }) }
+///             </div>
+///             <div hidden={current != 1}>
+///                 // Not built until the tab is selected for the first time
+///                 { lazy(current == 1, || view! { <p>"Advanced settings"</p> }) }
+///             </div>
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
+pub const fn lazy<F, V>(visible: bool, render: F) -> Lazy<F>
+where
+    F: FnOnce() -> V,
+    V: View,
+{
+    Lazy { visible, render }
+}
+
 /// Smart [`View`] that prevents updates, see [`invar`].
 #[repr(transparent)]
 pub struct Invar<F>(F);
@@ -102,6 +150,46 @@ where
     }
 
     fn update(self, _: &mut Self::Product) {}
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (self.0)().render_html(out);
+    }
+}
+
+/// Smart [`View`] that defers building until first revealed, see [`lazy`].
+pub struct Lazy<F> {
+    visible: bool,
+    render: F,
+}
+
+impl<V, F> View for Lazy<F>
+where
+    F: FnOnce() -> V,
+    V: View,
+{
+    type Product = Branch2<V::Product, EmptyNode>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        self.visible.then(self.render).build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        if !self.visible {
+            return;
+        }
+
+        if let Branch2::A(p) = p {
+            (self.render)().update(p);
+        } else {
+            Some((self.render)()).update(p);
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.visible.then(self.render).render_html(out);
+    }
 }
 
 /// Smart [`View`] that guards against unnecessary renders, see [`fence`].
@@ -132,6 +220,11 @@ where
             (self.inner)().update(&mut p.inner);
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (self.inner)().render_html(out);
+    }
 }
 
 impl<D, P> Anchor for Fence<D, P>
@@ -146,7 +239,60 @@ where
     }
 }
 
+/// Derive [`Diff`](trait@Diff) for a struct or an enum with fields that implement `Diff`.
+///
+/// The memo keeps the memos of all fields, a value has changed if any of its fields has.
+/// For enums a change of the variant always counts as a change, and enums without fields
+/// are simply compared by their discriminant. The type must also implement `Copy`, and
+/// can only be generic over lifetimes.
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::diff::{fence, Diff};
+///
+/// #[derive(Clone, Copy, Diff)]
+/// enum Tab {
+///     Overview,
+///     Settings,
+/// }
+///
+/// #[derive(Clone, Copy, Diff)]
+/// enum Sort<'a> {
+///     None,
+///     By { column: &'a str, ascending: bool },
+/// }
+///
+/// #[component]
+/// fn table<'a>(tab: Tab, sort: Sort<'a>) -> impl View + 'a {
+///     // Only rendered again when either the tab or sorting changes
+///     fence((tab, sort), move || view! {
+///         <p>{ static matches!(tab, Tab::Settings).then_some("Settings").unwrap_or("Overview") }</p>
+///         <p>{ static match sort { Sort::None => "Unsorted", Sort::By { column, .. } => column } }</p>
+///     })
+/// }
+/// # fn main() {}
+/// ```
+pub use kobold_macros::Diff;
+
 /// Trait that defines how different values can be _diffed_ at runtime.
+///
+/// References to primitive values are diffed by value, so borrowed fields can be
+/// used without dereferencing them first:
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::diff::fence;
+///
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// #[component]
+/// fn counter(counter: &Counter) -> impl View + '_ {
+///     fence(&counter.count, || view! { <p>"Count: "{ counter.count }</p> })
+/// }
+/// # fn main() {}
+/// ```
 pub trait Diff: Copy {
     /// Data used to check if current value is different from the one in the past.
     type Memo: 'static;
@@ -182,6 +328,22 @@ where
 macro_rules! impl_diff_str {
     ($($ty:ty),*) => {
         $(
+            /// The memo is a `String` that keeps its buffer between updates, changed strings
+            /// are copied into it and only allocate if they outgrow its capacity:
+            ///
+            /// ```
+            /// use kobold::diff::Diff;
+            ///
+            /// let mut memo = "00:00:00".into_memo();
+            /// let buffer = memo.as_ptr();
+            ///
+            /// for label in ["00:00:01", "00:00:02", "0:03"] {
+            ///     assert!(label.diff(&mut memo));
+            /// }
+            ///
+            /// assert_eq!(memo, "0:03");
+            /// assert_eq!(memo.as_ptr(), buffer);
+            /// ```
             impl Diff for $ty {
                 type Memo = String;
 
@@ -221,12 +383,114 @@ macro_rules! impl_diff {
                     }
                 }
             }
+
+            /// Diffs the referenced value, same as diffing a copy of it.
+            impl Diff for &$ty {
+                type Memo = $ty;
+
+                fn into_memo(self) -> $ty {
+                    *self
+                }
+
+                fn diff(self, memo: &mut $ty) -> bool {
+                    (*self).diff(memo)
+                }
+            }
         )*
     };
 }
 
 impl_diff_str!(&str, &String);
+
+macro_rules! impl_diff_path {
+    ($($ty:ty => $memo:ty),*) => {
+        $(
+            /// Compared by value, the memo keeps its buffer between updates just like strings.
+            impl Diff for $ty {
+                type Memo = $memo;
+
+                fn into_memo(self) -> $memo {
+                    self.into()
+                }
+
+                fn diff(self, memo: &mut $memo) -> bool {
+                    if *memo != *self {
+                        self.clone_into(memo);
+                        true
+                    } else {
+                        false
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_diff_path!(&Path => PathBuf, &PathBuf => PathBuf, &OsStr => OsString);
 impl_diff!(bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_diff!(Duration);
+
+impl Diff for () {
+    type Memo = ();
+
+    fn into_memo(self) {}
+
+    fn diff(self, _: &mut ()) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_diff_tuple {
+    ($($n:tt $ty:ident),+) => {
+        impl<$($ty),+> Diff for ($($ty,)+)
+        where
+            $($ty: Diff,)+
+        {
+            type Memo = ($($ty::Memo,)+);
+
+            fn into_memo(self) -> Self::Memo {
+                ($(self.$n.into_memo(),)+)
+            }
+
+            fn diff(self, memo: &mut Self::Memo) -> bool {
+                // Diff all fields so that every memo is kept up to date
+                let mut changed = false;
+
+                $(changed |= self.$n.diff(&mut memo.$n);)+
+
+                changed
+            }
+        }
+    };
+}
+
+impl_diff_tuple!(0 A);
+impl_diff_tuple!(0 A, 1 B);
+impl_diff_tuple!(0 A, 1 B, 2 C);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+impl_diff_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+
+impl<T> Diff for Wrapping<T>
+where
+    T: Diff,
+{
+    type Memo = T::Memo;
+
+    fn into_memo(self) -> T::Memo {
+        self.0.into_memo()
+    }
+
+    fn diff(self, memo: &mut T::Memo) -> bool {
+        self.0.diff(memo)
+    }
+}
 
 /// Smart [`View`] that only updates its content when the reference to T has changed.
 /// See [`ref`](crate::keywords::ref).
@@ -307,6 +571,11 @@ macro_rules! impl_no_diff {
                     self.0.set_prop(TextContent, node);
                 }
             }
+
+            #[cfg(feature = "ssr")]
+            fn render_html(self, out: &mut String) {
+                self.0.render_prop(TextContent, out);
+            }
         }
 
         impl<T, P> Attribute<P> for $name<T>
@@ -326,6 +595,11 @@ macro_rules! impl_no_diff {
                     self.0.set_prop(prop, node);
                 }
             }
+
+            #[cfg(feature = "ssr")]
+            fn render_html(self, prop: P, out: &mut String) {
+                self.0.render_prop(prop, out);
+            }
         }
 
         impl<T> Diff for $name<T>
@@ -341,9 +615,23 @@ macro_rules! impl_no_diff {
             }
         }
 
-        impl AsRef<str> for $name<&str> {
+        impl<T> AsRef<str> for $name<T>
+        where
+            T: AsRef<str>,
+        {
             fn as_ref(&self) -> &str {
-                self.0
+                self.0.as_ref()
+            }
+        }
+
+        // Inlined boolean attributes such as `checked` take their value as `Into<bool>`,
+        // this lets `{ static ... }` and `{ use ... }` flags be inlined as well.
+        impl<T> From<$name<T>> for bool
+        where
+            T: Into<bool>,
+        {
+            fn from(value: $name<T>) -> bool {
+                value.0.into()
             }
         }
 
@@ -359,6 +647,11 @@ macro_rules! impl_no_diff {
                     self.0.set_prop(TextContent, p);
                 }
             }
+
+            #[cfg(feature = "ssr")]
+            fn render_html(self, out: &mut String) {
+                self.0.as_str().render_prop(TextContent, out);
+            }
         }
     };
 }
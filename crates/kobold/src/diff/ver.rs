@@ -0,0 +1,265 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::fmt::{self, Debug};
+use std::ops::{Deref, DerefMut};
+
+use crate::diff::Diff;
+
+/// Versioned value.
+///
+/// This is a generalization of [`VString`](crate::diff::VString) for any type `T`. In addition to the
+/// value itself, `Ver` tracks a _version_ integer that's automatically incremented on any mutable access.
+///
+/// A `&Ver<T>` reference implements [`Diff`] by comparing the version and the address of the value,
+/// which makes it a cheap guard for a [`fence`](crate::diff::fence) around views rendering large
+/// or complex values that don't implement `Diff` on their own.
+///
+/// With the `serde` feature enabled `Ver<T>` serializes as the inner value, the version
+/// starts from zero when deserialized.
+#[derive(Default, Clone)]
+pub struct Ver<T> {
+    inner: T,
+    ver: usize,
+}
+
+impl<T> Ver<T> {
+    /// Creates a new `Ver` wrapping `value`.
+    pub const fn new(value: T) -> Self {
+        Ver {
+            inner: value,
+            ver: 0,
+        }
+    }
+
+    /// Get the underlying value from this `Ver`.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> Diff for &'_ Ver<T> {
+    type Memo = u64;
+
+    fn into_memo(self) -> Self::Memo {
+        (self.ver as u64).wrapping_shl(32) | &self.inner as *const T as u64
+    }
+
+    fn diff(self, memo: &mut Self::Memo) -> bool {
+        let m = self.into_memo();
+
+        if *memo != m {
+            *memo = m;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<T> Deref for Ver<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for Ver<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.ver += 1;
+
+        &mut self.inner
+    }
+}
+
+impl<T> From<T> for Ver<T> {
+    fn from(value: T) -> Self {
+        Ver::new(value)
+    }
+}
+
+impl<T> PartialEq for Ver<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.eq(&other.inner)
+    }
+}
+
+impl<T> Eq for Ver<T> where T: Eq {}
+
+impl<T> Debug for Ver<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+
+/// Vector of independently [versioned](Ver) elements.
+///
+/// Mutating an element through `VerVec` only increments the version of that element, so when
+/// rows of a list are rendered inside a [`fence`](crate::diff::fence) guarded by the element,
+/// only the rows that have actually changed are updated:
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::diff::{fence, VerVec};
+///
+/// struct Row {
+///     name: String,
+///     score: u32,
+/// }
+///
+/// #[component]
+/// fn table(rows: &VerVec<Row>) -> impl View + '_ {
+///     view! {
+///         <table>
+///         {
+///             for rows.iter().map(|row| fence(row, || view! {
+///                 // Only re-rendered if this particular row was mutated
+///                 <tr>
+///                     <td>{ ref row.name }</td>
+///                     <td>{ row.score }</td>
+///                 </tr>
+///             }))
+///         }
+///         </table>
+///     }
+/// }
+///
+/// # fn main() {
+/// let mut rows = VerVec::new();
+///
+/// rows.push(Row { name: "Alice".into(), score: 10 });
+/// rows.push(Row { name: "Bob".into(), score: 7 });
+///
+/// // Only bumps the version of the second row
+/// rows[1].score += 1;
+/// # }
+/// ```
+///
+/// `VerVec` dereferences to a `Vec<Ver<T>>`, so all the usual `Vec` and slice methods are available.
+pub struct VerVec<T> {
+    inner: Vec<Ver<T>>,
+}
+
+impl<T> VerVec<T> {
+    /// Creates a new empty `VerVec`.
+    pub const fn new() -> Self {
+        VerVec { inner: Vec::new() }
+    }
+
+    /// Creates a new empty `VerVec` with at least the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        VerVec {
+            inner: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a new versioned element to the back of the vector.
+    pub fn push(&mut self, value: T) {
+        self.inner.push(Ver::new(value));
+    }
+
+    /// Inserts a new versioned element at position `index`, shifting all elements after it to the right.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.inner.insert(index, Ver::new(value));
+    }
+
+    /// Get the underlying `Vec` from this `VerVec`.
+    pub fn into_inner(self) -> Vec<Ver<T>> {
+        self.inner
+    }
+}
+
+impl<T> Default for VerVec<T> {
+    fn default() -> Self {
+        VerVec::new()
+    }
+}
+
+impl<T> Deref for VerVec<T> {
+    type Target = Vec<Ver<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for VerVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T> From<Vec<T>> for VerVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T> FromIterator<T> for VerVec<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        VerVec {
+            inner: iter.into_iter().map(Ver::new).collect(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a VerVec<T> {
+    type Item = &'a Ver<T>;
+    type IntoIter = std::slice::Iter<'a, Ver<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<T> Debug for VerVec<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.inner, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+
+    use super::Ver;
+
+    impl<T> Serialize for Ver<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.inner.serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for Ver<T>
+    where
+        T: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            T::deserialize(deserializer).map(Ver::new)
+        }
+    }
+}
@@ -4,12 +4,15 @@
 
 //! Utilities for mounting elements in the DOM
 
+use std::cell::OnceCell;
 use std::ops::Deref;
+use std::rc::Rc;
 
 use wasm_bindgen::{JsCast, JsValue};
-use web_sys::Node;
+use web_sys::{CanvasRenderingContext2d, Element, HtmlCanvasElement, HtmlElement, Node};
 
-use crate::internal;
+use crate::internal::{self, In, Out};
+use crate::{init, View};
 
 /// A type that can be mounted in the DOM
 pub trait Mountable: 'static {
@@ -56,6 +59,50 @@ where
     }
 }
 
+/// Helper methods for `<canvas>` elements.
+///
+/// The DOM node of a `<canvas>` is available in [`on_mount`](crate::View::on_mount)
+/// and [`on_render`](crate::View::on_render), the latter can be used to redraw the canvas on every update:
+///
+/// ```
+/// use kobold::dom::CanvasExt;
+/// use kobold::prelude::*;
+///
+/// #[component]
+/// fn bar(width: f64) -> impl View {
+///     view! {
+///         <canvas width="200" height="20">
+///     }
+///     .on_render(move |node| {
+///         if let Some(ctx) = node.context_2d() {
+///             ctx.clear_rect(0.0, 0.0, 200.0, 20.0);
+///             ctx.fill_rect(0.0, 0.0, width, 20.0);
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
+pub trait CanvasExt {
+    /// Cast this node to a typed [`HtmlCanvasElement`], returns `None` if it isn't a `<canvas>`.
+    fn canvas(&self) -> Option<&HtmlCanvasElement>;
+
+    /// Get the `2d` rendering context of this canvas, returns `None` if this node isn't a `<canvas>`
+    /// or if it has already been used with a different context type.
+    fn context_2d(&self) -> Option<CanvasRenderingContext2d>;
+}
+
+impl CanvasExt for Node {
+    fn canvas(&self) -> Option<&HtmlCanvasElement> {
+        self.dyn_ref()
+    }
+
+    fn context_2d(&self) -> Option<CanvasRenderingContext2d> {
+        let ctx = self.canvas()?.get_context("2d").ok()??;
+
+        Some(ctx.unchecked_into())
+    }
+}
+
 /// Thin-wrapper around a [`DocumentFragment`](https://developer.mozilla.org/en-US/docs/Web/API/DocumentFragment) node.
 ///
 /// **Kobold** needs to "decorate" fragments for [`unmount`](Mountable::unmount)
@@ -82,6 +129,10 @@ impl AsRef<JsValue> for Fragment {
 /// A settable property of a DOM `Node`
 pub trait Property<Abi> {
     fn set(self, this: &Node, value: Abi);
+
+    /// Render this property with a `value` as HTML, see [`ssr`](crate::ssr).
+    #[cfg(feature = "ssr")]
+    fn render(self, value: Abi, out: &mut String);
 }
 
 /// The `Node.textContent` property: <https://developer.mozilla.org/en-US/docs/Web/API/Node/textContent>
@@ -91,18 +142,33 @@ impl Property<&str> for TextContent {
     fn set(self, this: &Node, value: &str) {
         internal::obj(this).set_text(value);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: &str, out: &mut String) {
+        crate::ssr::escape(value, out);
+    }
 }
 
 impl Property<f64> for TextContent {
     fn set(self, this: &Node, value: f64) {
         internal::obj(this).set_text_num(value);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: f64, out: &mut String) {
+        crate::ssr::escape(value, out);
+    }
 }
 
 impl Property<bool> for TextContent {
     fn set(self, this: &Node, value: bool) {
         internal::obj(this).set_text_bool(value);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render(self, value: bool, out: &mut String) {
+        crate::ssr::escape(value, out);
+    }
 }
 
 pub(crate) struct FragmentBuilder {
@@ -120,6 +186,14 @@ impl FragmentBuilder {
     pub fn append(&self, child: &JsValue) {
         internal::obj(&self.tail).append_before(child);
     }
+
+    pub fn prepend(&self, child: &JsValue) {
+        internal::obj(&internal::first_node(self.fragment.js())).append_after(child);
+    }
+
+    pub fn tail(&self) -> &Node {
+        &self.tail
+    }
 }
 
 impl Deref for FragmentBuilder {
@@ -146,6 +220,376 @@ impl Mountable for Node {
     }
 }
 
+/// Embed a DOM node created outside of Kobold, e.g. by a third-party library.
+///
+/// On update the node is swapped in the DOM only if it's a different node than the one
+/// already mounted. The node only exists in the browser, so it renders as nothing with
+/// [`ssr`](crate::ssr).
+///
+/// ```
+/// use kobold::prelude::*;
+/// use web_sys::Node;
+///
+/// #[component]
+/// fn widget(node: &Node) -> impl View + '_ {
+///     view! {
+///         <div.widget>{ node.clone() }</div>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+impl View for Node {
+    type Product = Node;
+
+    fn build(self, p: In<Node>) -> Out<Node> {
+        p.put(self)
+    }
+
+    fn update(self, p: &mut Node) {
+        if *p != self {
+            p.replace_with(&self);
+            *p = self;
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: &mut String) {}
+}
+
+/// Embed a DOM element created outside of Kobold, see the [`View`] implementation for [`Node`].
+impl View for Element {
+    type Product = Node;
+
+    fn build(self, p: In<Node>) -> Out<Node> {
+        p.put(self.into())
+    }
+
+    fn update(self, p: &mut Node) {
+        Node::from(self).update(p);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, _: &mut String) {}
+}
+
+pub use crate::internal::{template, CachedProduct, Template};
+
+/// Create a [`Shared`] view, an immutable subtree that is built once at runtime and
+/// reused at many mount points.
+///
+/// Unlike [`template`], which caches a subtree per closure type, every call to `shared`
+/// creates its own subtree, so it can capture values known only at runtime:
+///
+/// ```
+/// use kobold::dom::shared;
+/// use kobold::prelude::*;
+///
+/// #[component]
+/// fn rows(names: &[String], level: u32) -> impl View + '_ {
+///     // Built for the first row, cloned with `cloneNode` for every other row
+///     let badge = shared(move || view! {
+///         <span.badge>"Level "{ level }</span>
+///     });
+///
+///     view! {
+///         <ul>
+///             { for names.iter().map(move |name| view! { <li>{ badge.clone() }{ name }</li> }) }
+///         </ul>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub fn shared<F, V>(render: F) -> Shared<F>
+where
+    F: Fn() -> V + 'static,
+    V: View,
+{
+    Shared(Rc::new(SharedNode {
+        render,
+        node: OnceCell::new(),
+    }))
+}
+
+/// Smart [`View`] sharing a DOM subtree between mount points, see [`shared`].
+///
+/// The subtree is built on the first mount, every other mount is a deep clone made with
+/// `cloneNode`. Only the markup is shared: event listeners and other state of the view
+/// stay with the first mount and are not carried over to the clones. Cloning a `Shared`
+/// is cheap, all clones share the same subtree.
+///
+/// Updates are no-ops, the same as [`static`](crate::keywords::static) values,
+/// the mounted nodes never change once built.
+pub struct Shared<F>(Rc<SharedNode<F>>);
+
+struct SharedNode<F> {
+    render: F,
+    node: OnceCell<Node>,
+}
+
+impl<F> Clone for Shared<F> {
+    fn clone(&self) -> Self {
+        Shared(self.0.clone())
+    }
+}
+
+impl<F, V> View for &Shared<F>
+where
+    F: Fn() -> V + 'static,
+    V: View,
+{
+    type Product = CachedProduct<V::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        let shared = &self.0;
+
+        if let Some(node) = shared.node.get() {
+            return p.put(CachedProduct::clone_of(node));
+        }
+
+        let (product, node) = CachedProduct::build((shared.render)(), "shared");
+
+        let _ = shared.node.set(node);
+
+        p.put(product)
+    }
+
+    fn update(self, _: &mut Self::Product) {}
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (self.0.render)().render_html(out);
+    }
+}
+
+impl<F, V> View for Shared<F>
+where
+    F: Fn() -> V + 'static,
+    V: View,
+{
+    type Product = CachedProduct<V::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        (&self).build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        (&self).update(p)
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (&self).render_html(out)
+    }
+}
+
+/// Create a [`View`] from a string of trusted HTML, see [`Prerender`].
+///
+/// ```
+/// use kobold::dom::prerender;
+/// use kobold::prelude::*;
+///
+/// #[component]
+/// fn report(table: &str) -> impl View + '_ {
+///     view! {
+///         <div.report>
+///             // `table` is HTML generated by the app itself, never from user input
+///             { prerender(table) }
+///         </div>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub const fn prerender<T: AsRef<str>>(html: T) -> Prerender<T> {
+    Prerender(html)
+}
+
+/// A one-shot [`View`] that sets the `innerHTML` of a `<template>` to build its nodes,
+/// created by [`prerender`].
+///
+/// Parsing a large chunk of static HTML at once can be faster than constructing the nodes
+/// one by one. The HTML is only used on build, updates never change the rendered nodes.
+///
+/// **⚠️ Warning:** The HTML isn't escaped or sanitized in any way, never use it with untrusted
+/// input as that opens the app to [XSS](https://developer.mozilla.org/en-US/docs/Glossary/Cross-site_scripting)
+/// attacks.
+#[repr(transparent)]
+pub struct Prerender<T>(T);
+
+impl<T: AsRef<str>> View for Prerender<T> {
+    type Product = Fragment;
+
+    fn build(self, p: In<Fragment>) -> Out<Fragment> {
+        let template = internal::create_element("template");
+        let builder = FragmentBuilder::new();
+
+        internal::obj(&template).inner_html(self.0.as_ref());
+        builder.append(&internal::obj(&template).template_content());
+
+        p.put(Fragment::clone(&builder))
+    }
+
+    fn update(self, _: &mut Fragment) {}
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        out.push_str(self.0.as_ref());
+    }
+}
+
+/// A [`View`] wrapped in a parent element, created by [`View::wrapped_in`].
+pub struct Wrapped<V> {
+    view: V,
+    tag: &'static str,
+    class: &'static str,
+}
+
+impl<V> Wrapped<V> {
+    pub(crate) const fn new(view: V, tag: &'static str, class: &'static str) -> Self {
+        Wrapped { view, tag, class }
+    }
+}
+
+/// Product of [`Wrapped`], the parent element owning the product of the wrapped view.
+pub struct WrappedProduct<P> {
+    node: Node,
+    child: P,
+}
+
+impl<V: View> View for Wrapped<V> {
+    type Product = WrappedProduct<V::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        let node = internal::create_element(self.tag);
+
+        if !self.class.is_empty() {
+            internal::obj(&node).class_name(self.class);
+        }
+
+        p.in_place(|p| unsafe {
+            let child = init!(p.child @ self.view.build(p));
+
+            internal::obj(&node).append_child(child.js());
+
+            init!(p.node = node);
+
+            Out::from_raw(p)
+        })
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        self.view.update(&mut p.child);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        out.push('<');
+        out.push_str(self.tag);
+
+        if !self.class.is_empty() {
+            out.push_str(" class=\"");
+            crate::ssr::escape(self.class, out);
+            out.push('"');
+        }
+
+        out.push('>');
+        self.view.render_html(out);
+        out.push_str("</");
+        out.push_str(self.tag);
+        out.push('>');
+    }
+}
+
+impl<P> Anchor for WrappedProduct<P> {
+    type Js = HtmlElement;
+    type Target = Node;
+
+    fn anchor(&self) -> &Node {
+        &self.node
+    }
+}
+
+/// Product of a tuple of [`View`]s, mounted as siblings in a fragment.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[component]
+/// fn header(title: &str) -> impl View + '_ {
+///     view! { <h1>{ title }</h1> }
+/// }
+///
+/// #[component]
+/// fn body(text: &str) -> impl View + '_ {
+///     view! { <p>{ text }</p> }
+/// }
+///
+/// fn page<'a>(title: &'a str, text: &'a str) -> impl View + 'a {
+///     // Differently typed views rendered in order, each updated in place
+///     (view! { <!header {title}> }, view! { <!body {text}> }, "Footer")
+/// }
+/// # fn main() {}
+/// ```
+pub struct TupleProduct<T> {
+    products: T,
+    fragment: FragmentBuilder,
+}
+
+macro_rules! impl_view_tuple {
+    ($($view:ident $var:ident $idx:tt),+) => {
+        impl<$($view: View),+> View for ($($view,)+) {
+            type Product = TupleProduct<($($view::Product,)+)>;
+
+            fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+                let ($($var,)+) = self;
+
+                p.in_place(|p| unsafe {
+                    let fragment = init!(p.fragment = FragmentBuilder::new());
+
+                    $(
+                        let product = In::raw(
+                            std::ptr::addr_of_mut!((*p).products.$idx),
+                            move |p| $var.build(p),
+                        );
+
+                        fragment.append(product.js());
+                    )+
+
+                    Out::from_raw(p)
+                })
+            }
+
+            fn update(self, p: &mut Self::Product) {
+                $(
+                    self.$idx.update(&mut p.products.$idx);
+                )+
+            }
+
+            #[cfg(feature = "ssr")]
+            fn render_html(self, out: &mut String) {
+                $(
+                    self.$idx.render_html(out);
+                )+
+            }
+        }
+    };
+}
+
+impl_view_tuple!(A a 0, B b 1);
+impl_view_tuple!(A a 0, B b 1, C c 2);
+impl_view_tuple!(A a 0, B b 1, C c 2, D d 3);
+impl_view_tuple!(A a 0, B b 1, C c 2, D d 3, E e 4);
+impl_view_tuple!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5);
+impl_view_tuple!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6);
+impl_view_tuple!(A a 0, B b 1, C c 2, D d 3, E e 4, F f 5, G g 6, H h 7);
+
+impl<T> Anchor for TupleProduct<T> {
+    type Js = Node;
+    type Target = Fragment;
+
+    fn anchor(&self) -> &Fragment {
+        &self.fragment
+    }
+}
+
 impl Mountable for Fragment {
     type Js = Node;
 
@@ -3,13 +3,74 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 //! Utilities for handling DOM events
+//!
+//! Event handlers receive one of the wrappers defined here, which dereference to the
+//! matching `web-sys` type, e.g. `onwheel` receives a [`WheelEvent`] and `oncontextmenu`
+//! receives a [`MouseEvent`]:
+//!
+//! ```
+//! use kobold::prelude::*;
+//!
+//! #[component]
+//! fn zoomable(zoom: u32) -> impl View {
+//!     stateful(zoom, |zoom| {
+//!         bind! { zoom:
+//!             let onwheel = move |e: WheelEvent<_>| {
+//!                 e.prevent_default();
+//!
+//!                 if e.delta_y() < 0.0 {
+//!                     *zoom += 10;
+//!                 } else {
+//!                     *zoom = zoom.saturating_sub(10).max(10);
+//!                 }
+//!             };
+//!             // Suppress the browser menu to show a custom one instead
+//!             let oncontextmenu = move |e: MouseEvent<_>| e.prevent_default();
+//!         }
+//!
+//!         view! {
+//!             <div.zoomable {onwheel} {oncontextmenu}>"Zoom: "{ zoom.get() }"%"</div>
+//!         }
+//!     })
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ## Modifiers
+//!
+//! Event attributes accept the `once`, `capture` and `passive` modifiers, which set the
+//! matching [`addEventListener` options](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#options).
+//! A listener with `once` is removed by the browser after it fires for the first time:
+//!
+//! ```
+//! use kobold::prelude::*;
+//!
+//! #[component]
+//! fn intro(seen: Signal<bool>) -> impl View {
+//!     let onanimationend = move |_| seen.set(true);
+//!     let onclick = |e: MouseEvent<_>| e.stop_propagation();
+//!
+//!     view! {
+//!         <div.intro onanimationend.once={onanimationend} onclick.capture={onclick}>
+//!             "Welcome!"
+//!         </div>
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Modifiers can be chained, e.g. `ontouchstart.once.passive={...}`, but can't be used
+//! with the `{onclick}` shorthand.
 
 use std::marker::PhantomData;
 use std::ops::Deref;
 
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::{JsCast, JsValue};
-use web_sys::{HtmlElement, HtmlInputElement};
+use web_sys::{
+    DomStringMap, Element, File, FileList, HtmlElement, HtmlInputElement, HtmlSelectElement,
+    HtmlTextAreaElement,
+};
 
 use crate::internal::{self, In, Out};
 
@@ -43,7 +104,13 @@ macro_rules! event {
                 }
             }
 
-            impl<T> EventCast for $event<T> {}
+            impl<T> EventCast for $event<T> {
+                type Target = T;
+
+                fn as_event(&self) -> &web_sys::Event {
+                    &self.event
+                }
+            }
 
             impl<T> Deref for $event<T> {
                 type Target = web_sys::$event;
@@ -72,19 +139,48 @@ macro_rules! event {
                 {
                     EventTarget(self.event.unchecked_ref::<EventWithTarget>().current_target().unchecked_into())
                 }
+
+                /// Return the closest ancestor of the target element matching the CSS `selector`,
+                /// starting with the target itself, see [`EventTarget::closest`].
+                pub fn target_closest(&self, selector: &str) -> Option<HtmlElement> {
+                    closest(&self.target(), selector)
+                }
             }
         )*
     };
 }
 
 mod sealed {
-    pub trait EventCast {}
+    pub trait EventCast {
+        type Target;
+
+        fn as_event(&self) -> &web_sys::Event;
+    }
+
+    impl EventCast for web_sys::Event {
+        type Target = web_sys::HtmlElement;
 
-    impl EventCast for web_sys::Event {}
+        fn as_event(&self) -> &web_sys::Event {
+            self
+        }
+    }
 }
 
 pub(crate) use sealed::EventCast;
 
+/// Return the current target of any event, cast to the target type of the event.
+pub(crate) fn current_target<E>(event: &E) -> E::Target
+where
+    E: EventCast,
+    E::Target: JsCast,
+{
+    event
+        .as_event()
+        .unchecked_ref::<EventWithTarget>()
+        .current_target()
+        .unchecked_into()
+}
+
 event! {
     /// [`web_sys::Event`](web_sys::Event)
     Event,
@@ -92,6 +188,55 @@ event! {
     KeyboardEvent,
     /// [`web_sys::MouseEvent`](web_sys::MouseEvent)
     MouseEvent,
+    /// [`web_sys::WheelEvent`](web_sys::WheelEvent)
+    WheelEvent,
+    /// [`web_sys::DragEvent`](web_sys::DragEvent)
+    DragEvent,
+    /// [`web_sys::UiEvent`](web_sys::UiEvent)
+    UiEvent,
+}
+
+impl<T> DragEvent<T> {
+    /// Return the list of files dropped or dragged, if any.
+    ///
+    /// Elements only accept drops if the `dragover` event is cancelled with
+    /// [`prevent_default`](web_sys::Event::prevent_default):
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[component]
+    /// fn drop_zone() -> impl View {
+    ///     let ondragover = |e: DragEvent<HtmlElement>| e.prevent_default();
+    ///     let ondrop = |e: DragEvent<HtmlElement>| {
+    ///         e.prevent_default();
+    ///
+    ///         if let Some(files) = e.files() {
+    ///             for idx in 0..files.length() {
+    ///                 let _file = files.get(idx);
+    ///             }
+    ///         }
+    ///     };
+    ///
+    ///     view! {
+    ///         <div.drop-zone {ondragover} {ondrop}>"Drop files here"</div>
+    ///     }
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn files(&self) -> Option<FileList> {
+        self.data_transfer()?.files()
+    }
+
+    /// Return the dragged data of given `format`, such as `"text/plain"` or `"text/uri-list"`,
+    /// or `None` if there is no such data.
+    pub fn data(&self, format: &str) -> Option<String> {
+        self.data_transfer()?
+            .get_data(format)
+            .ok()
+            .filter(|data| !data.is_empty())
+    }
 }
 
 pub trait IntoListener<E: EventCast> {
@@ -149,6 +294,11 @@ pub struct ListenerProduct<F, E> {
 }
 
 pub trait ListenerHandle {
+    /// Create the JavaScript event handler calling into this product.
+    ///
+    /// This is only called once when the element is built, the handler holds
+    /// a pointer to the product so that [`Listener::update`] only needs to swap
+    /// the Rust closure without touching the DOM.
     fn js_value(&mut self) -> JsValue;
 }
 
@@ -164,6 +314,75 @@ where
     }
 }
 
+/// Wrap an event listener so that [`prevent_default`](web_sys::Event::prevent_default) is called
+/// on the event before the listener runs.
+///
+/// This is most useful for `onsubmit`, where forgetting to prevent the default action
+/// reloads the whole page:
+///
+/// ```
+/// use kobold::event::on_submit;
+/// use kobold::prelude::*;
+/// use web_sys::HtmlFormElement;
+///
+/// #[component]
+/// fn login() -> impl View {
+///     let onsubmit = on_submit(|e: Event<HtmlFormElement>| {
+///         // The page stays as it is, the form can be sent with `fetch` instead
+///         let _form = e.current_target();
+///     });
+///
+///     view! {
+///         <form {onsubmit}>
+///             <input name="user">
+///             <button>"Log in"</button>
+///         </form>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Handlers bound to a [`Hook`](crate::stateful::Hook) can use
+/// [`Hook::bind_submit`](crate::stateful::Hook::bind_submit) instead.
+pub const fn on_submit<L>(listener: L) -> OnSubmit<L> {
+    OnSubmit(listener)
+}
+
+/// Event listener calling [`prevent_default`](web_sys::Event::prevent_default) first, see [`on_submit`].
+#[repr(transparent)]
+pub struct OnSubmit<L>(L);
+
+/// Product of an [`OnSubmit`] listener.
+#[repr(transparent)]
+pub struct OnSubmitProduct<P>(P);
+
+impl<E, L> Listener<E> for OnSubmit<L>
+where
+    L: Listener<E>,
+    E: EventCast,
+{
+    type Product = OnSubmitProduct<L::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        let out = self.0.build(unsafe { p.cast() });
+
+        unsafe { out.cast() }
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        self.0.update(&mut p.0);
+    }
+}
+
+impl<P> ListenerHandle for OnSubmitProduct<P>
+where
+    P: ListenerHandle,
+{
+    fn js_value(&mut self) -> JsValue {
+        internal::prevent_default(&self.0.js_value())
+    }
+}
+
 /// A wrapper over some event target type from web-sys.
 #[repr(transparent)]
 pub struct EventTarget<T>(T);
@@ -176,8 +395,283 @@ impl<T> Deref for EventTarget<T> {
     }
 }
 
+impl<T> EventTarget<T>
+where
+    T: AsRef<Element>,
+{
+    /// Return the closest ancestor of this element matching the CSS `selector`, starting
+    /// with the element itself. Returns `None` if no element matches or if the selector
+    /// is invalid.
+    ///
+    /// Combined with [`target_closest`](Event::target_closest) this allows handling events
+    /// of many elements with a single listener on their container, using `data-*` attributes
+    /// to tell the elements apart:
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[component]
+    /// fn rows(count: usize) -> impl View {
+    ///     stateful(|| None, move |selected: &Hook<Option<usize>>| {
+    ///         let onclick = event!(|selected, e: MouseEvent<HtmlElement>| {
+    ///             // Find the row that was clicked, wherever inside it the click landed
+    ///             if let Some(row) = e.target_closest("[data-row]") {
+    ///                 *selected = row.get_attribute("data-row").and_then(|idx| idx.parse().ok());
+    ///             }
+    ///         });
+    ///
+    ///         view! {
+    ///             <ul {onclick}>
+    ///             {
+    ///                 for (0..count).map(|idx| view! {
+    ///                     <li data-row={idx}><b>"Row "{ idx }</b></li>
+    ///                 })
+    ///             }
+    ///             </ul>
+    ///             <p>"Selected: "{ selected.map(|idx| idx as i64).unwrap_or(-1) }</p>
+    ///         }
+    ///     })
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn closest(&self, selector: &str) -> Option<HtmlElement> {
+        closest(self.0.as_ref(), selector)
+    }
+
+    /// Number of pixels this element is scrolled vertically, see
+    /// [`Element::scroll_top`](web_sys::Element::scroll_top).
+    pub fn scroll_top(&self) -> i32 {
+        self.0.as_ref().scroll_top()
+    }
+
+    /// Height of the content of this element, including content not visible due to
+    /// overflow, see [`Element::scroll_height`](web_sys::Element::scroll_height).
+    pub fn scroll_height(&self) -> i32 {
+        self.0.as_ref().scroll_height()
+    }
+
+    /// Inner height of this element, see [`Element::client_height`](web_sys::Element::client_height).
+    pub fn client_height(&self) -> i32 {
+        self.0.as_ref().client_height()
+    }
+
+    /// Returns `true` if this element is scrolled to within `margin` pixels of the bottom
+    /// of its content.
+    ///
+    /// `onscroll` handlers receive a [`UiEvent`], which makes it easy to load more items
+    /// as the user gets near the end of a list:
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[component]
+    /// fn feed() -> impl View {
+    ///     stateful(20_usize, |count| {
+    ///         let onscroll = event!(|count, e: UiEvent<HtmlElement>| {
+    ///             // Only render when more items are loaded
+    ///             if e.current_target().at_bottom(100) {
+    ///                 *count += 20;
+    ///                 Then::Render
+    ///             } else {
+    ///                 Then::Stop
+    ///             }
+    ///         });
+    ///
+    ///         view! {
+    ///             <ul.feed {onscroll}>
+    ///                 { for (0..count.get()).map(|idx| view! { <li>"Item "{ idx }</li> }) }
+    ///             </ul>
+    ///         }
+    ///     })
+    /// }
+    /// # fn main() {}
+    /// ```
+    ///
+    /// Scroll events fire at a high rate, keep the handler cheap and return [`Then::Stop`](crate::stateful::Then::Stop)
+    /// whenever nothing changed so the view isn't rendered on every event. For heavier work use
+    /// [`Signal::update_raf`](crate::stateful::Signal::update_raf) to render at most once per animation frame.
+    pub fn at_bottom(&self, margin: i32) -> bool {
+        let el = self.0.as_ref();
+
+        el.scroll_top() + el.client_height() >= el.scroll_height() - margin
+    }
+}
+
+impl EventTarget<HtmlElement> {
+    /// Return the `data-*` attributes of this element, see
+    /// [`HtmlElement::dataset`](web_sys::HtmlElement::dataset).
+    ///
+    /// [`get`](DomStringMap::get) returns `None` for missing keys. The DOM converts attribute
+    /// names to camel case, dropping the `data-` prefix, so `data-id` is read with `"id"` and
+    /// `data-user-id` with `"userId"`:
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[component]
+    /// fn users(count: usize) -> impl View {
+    ///     stateful(|| None, move |selected: &Hook<Option<String>>| {
+    ///         let onclick = event!(|selected, e: MouseEvent<HtmlElement>| {
+    ///             // Reads `data-list-id` of the `<ul>` the listener is attached to
+    ///             let list = e.current_target().dataset().get("listId");
+    ///             let user = e
+    ///                 .target_closest("[data-user-id]")
+    ///                 .and_then(|li| li.dataset().get("userId"));
+    ///
+    ///             *selected = list.zip(user).map(|(list, user)| format!("{list}/{user}"));
+    ///         });
+    ///
+    ///         view! {
+    ///             <ul data-list-id="users" {onclick}>
+    ///             {
+    ///                 for (0..count).map(|id| view! { <li data-user-id={id}>"User "{ id }</li> })
+    ///             }
+    ///             </ul>
+    ///             <p>"Selected: "{ selected.as_deref().unwrap_or("none") }</p>
+    ///         }
+    ///     })
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn dataset(&self) -> DomStringMap {
+        self.0.dataset()
+    }
+}
+
+fn closest(element: &Element, selector: &str) -> Option<HtmlElement> {
+    element.closest(selector).ok()?.map(JsCast::unchecked_into)
+}
+
+/// Elements with a text value, implemented for `<input>`, `<textarea>` and `<select>`.
+///
+/// This makes it possible to write handlers that are generic over the element type,
+/// or to implement it for custom elements:
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::event::TargetValue;
+/// use wasm_bindgen::JsCast;
+/// use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+///
+/// fn log_value<T: TargetValue + JsCast>(e: Event<T>) {
+///     let _value = e.current_target().value();
+/// }
+///
+/// #[component]
+/// fn form() -> impl View {
+///     view! {
+///         <input oninput={log_value::<HtmlInputElement>}>
+///         <textarea oninput={log_value::<HtmlTextAreaElement>}></textarea>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub trait TargetValue {
+    /// Return the current value of the element.
+    fn value(&self) -> String;
+
+    /// Set the value of the element.
+    fn set_value(&self, value: &str);
+}
+
+/// Elements that can be checked, implemented for `<input>`
+/// of `type="checkbox"` or `type="radio"`.
+pub trait TargetChecked {
+    /// Return whether the element is checked.
+    fn checked(&self) -> bool;
+
+    /// Check or uncheck the element.
+    fn set_checked(&self, checked: bool);
+}
+
+macro_rules! impl_target_value {
+    ($($ty:ty),*) => {
+        $(
+            impl TargetValue for $ty {
+                fn value(&self) -> String {
+                    <$ty>::value(self)
+                }
+
+                fn set_value(&self, value: &str) {
+                    <$ty>::set_value(self, value)
+                }
+            }
+        )*
+    };
+}
+
+impl_target_value!(HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement);
+
+impl TargetChecked for HtmlInputElement {
+    fn checked(&self) -> bool {
+        HtmlInputElement::checked(self)
+    }
+
+    fn set_checked(&self, checked: bool) {
+        HtmlInputElement::set_checked(self, checked)
+    }
+}
+
+impl<T: TargetValue> TargetValue for EventTarget<T> {
+    fn value(&self) -> String {
+        self.0.value()
+    }
+
+    fn set_value(&self, value: &str) {
+        self.0.set_value(value)
+    }
+}
+
+impl<T: TargetChecked> TargetChecked for EventTarget<T> {
+    fn checked(&self) -> bool {
+        self.0.checked()
+    }
+
+    fn set_checked(&self, checked: bool) {
+        self.0.set_checked(checked)
+    }
+}
+
 impl EventTarget<HtmlInputElement> {
     pub fn focus(&self) {
         drop(self.0.focus());
     }
+
+    /// Return the list of files selected in an `<input type="file">` element,
+    /// or `None` if this input is of a different type.
+    ///
+    /// Reading the contents of a [`File`] is asynchronous, it's best done using a
+    /// [`FileReader`](https://docs.rs/web-sys/latest/web_sys/struct.FileReader.html)
+    /// or [`Blob`](web_sys::Blob) promises inside of [`Hook::bind_async`](crate::stateful::Hook::bind_async).
+    pub fn files(&self) -> Option<FileList> {
+        self.0.files()
+    }
+
+    /// Return the first file selected in an `<input type="file">` element, if any.
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// #[component]
+    /// fn upload() -> impl View {
+    ///     let onchange = |e: Event<HtmlInputElement>| {
+    ///         if let Some(file) = e.current_target().file() {
+    ///             // Name and size in bytes of the selected file
+    ///             let (_name, _size) = (file.name(), file.size());
+    ///         }
+    ///     };
+    ///
+    ///     view! {
+    ///         <input type="file" {onchange}>
+    ///     }
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn file(&self) -> Option<File> {
+        self.files()?.get(0)
+    }
 }
@@ -4,6 +4,9 @@
 
 //! Kobold internals and types used by the [`view!`](crate::view) macro.
 
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::mem::MaybeUninit;
 use std::ops::{Deref, DerefMut};
 use std::pin::Pin;
@@ -11,7 +14,8 @@ use std::pin::Pin;
 use wasm_bindgen::prelude::*;
 use web_sys::Node;
 
-use crate::View;
+use crate::dom::Anchor;
+use crate::{Mountable, View};
 
 /// Uninitialized stable pointer to `T`.
 ///
@@ -189,8 +193,10 @@ macro_rules! init {
 }
 
 /// Wrapper that turns `extern` precompiled JavaScript functions into [`View`]s.
-#[repr(transparent)]
-pub struct Precompiled<F>(pub F);
+///
+/// With the `ssr` feature enabled it also carries a function rendering the same DOM as HTML.
+#[cfg_attr(not(feature = "ssr"), repr(transparent))]
+pub struct Precompiled<F>(pub F, #[cfg(feature = "ssr")] pub fn(&mut String));
 
 /// Helper function used by the [`view!`](crate::view) macro to provide type hints for
 /// event listeners.
@@ -210,6 +216,173 @@ where
     }
 
     fn update(self, _: &mut Node) {}
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (self.1)(out)
+    }
+}
+
+/// Build a static `view` once and clone its DOM for every following instance,
+/// cutting the build cost of subtrees repeated many times, such as icons in list rows.
+///
+/// Templates are cached by the type of the `render` closure, so every call site gets
+/// its own template. For that reason the closure can't capture any state, which is
+/// checked at compile time. The view is never updated, and it must render a single root
+/// element, not a fragment. Cached nodes are kept for the lifetime of the app.
+///
+/// Event listeners are not cloned, only the first instance built from `render` keeps them.
+///
+/// Use [`shared`](crate::dom::shared) for subtrees depending on values known at runtime:
+///
+/// ```compile_fail
+/// use kobold::prelude::*;
+/// use kobold::dom::template;
+///
+/// #[component]
+/// fn badge(level: u32) -> impl View {
+///     // Every badge would show the level of the first one
+///     template(move || view! { <span.badge>{ level }</span> })
+/// }
+/// # fn main() { let _ = badge(1); }
+/// ```
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::dom::template;
+///
+/// #[component]
+/// fn row(name: &str) -> impl View + '_ {
+///     view! {
+///         <li>
+///             // Built once, cloned with `cloneNode` for every other row
+///             { template(|| view! { <svg.icon viewBox="0 0 24 24"><circle cx="12" cy="12" r="10"/></svg> }) }
+///             { name }
+///         </li>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub const fn template<F, V>(render: F) -> Template<F>
+where
+    F: FnOnce() -> V + 'static,
+    V: View,
+{
+    let () = NoCaptures::<F>::OK;
+
+    Template(render)
+}
+
+struct NoCaptures<F>(F);
+
+impl<F> NoCaptures<F> {
+    const OK: () = assert!(
+        std::mem::size_of::<F>() == 0,
+        "`template` closures can't capture any state, use `shared` instead"
+    );
+}
+
+/// Smart [`View`] that clones a cached DOM subtree, see [`template`].
+#[repr(transparent)]
+pub struct Template<F>(F);
+
+/// Product of [`Template`] and [`Shared`](crate::dom::Shared) views.
+///
+/// The instance that built the cached subtree keeps the product of the view, so that
+/// event listeners attached to its nodes stay valid. Clones only hold on to their node.
+pub struct CachedProduct<P> {
+    node: Node,
+    _built: Option<Box<P>>,
+}
+
+impl<P> CachedProduct<P>
+where
+    P: Mountable,
+{
+    /// Build the product of `view` and return it along with a deep clone of its root
+    /// node to be cached.
+    pub(crate) fn build<V>(view: V, what: &str) -> (Self, Node)
+    where
+        V: View<Product = P>,
+    {
+        let built = In::boxed(|p| view.build(p));
+        let node = built.js().unchecked_ref::<Node>().clone();
+
+        assert_ne!(
+            node.node_type(),
+            Node::DOCUMENT_FRAGMENT_NODE,
+            "`{what}` views must render a single root element"
+        );
+
+        let cached = obj(&node).clone_node(true);
+
+        (
+            CachedProduct {
+                node,
+                _built: Some(built),
+            },
+            cached,
+        )
+    }
+
+    pub(crate) fn clone_of(cached: &Node) -> Self {
+        CachedProduct {
+            node: obj(cached).clone_node(true),
+            _built: None,
+        }
+    }
+}
+
+impl<P> Anchor for CachedProduct<P>
+where
+    P: Mountable,
+{
+    type Js = Node;
+    type Target = Node;
+
+    fn anchor(&self) -> &Node {
+        &self.node
+    }
+}
+
+thread_local! {
+    static TEMPLATES: RefCell<HashMap<TypeId, Node>> = RefCell::new(HashMap::new());
+}
+
+impl<F, V> View for Template<F>
+where
+    F: FnOnce() -> V + 'static,
+    V: View,
+{
+    type Product = CachedProduct<V::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        let key = TypeId::of::<F>();
+
+        // Don't hold on to the borrow while building, templates can be nested.
+        let cached =
+            TEMPLATES.with(|templates| templates.borrow().get(&key).map(CachedProduct::clone_of));
+
+        let product = match cached {
+            Some(product) => product,
+            None => {
+                let (product, node) = CachedProduct::build((self.0)(), "template");
+
+                TEMPLATES.with(|templates| templates.borrow_mut().insert(key, node));
+
+                product
+            }
+        };
+
+        p.put(product)
+    }
+
+    fn update(self, _: &mut Self::Product) {}
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (self.0)().render_html(out);
+    }
 }
 
 #[wasm_bindgen]
@@ -224,15 +397,25 @@ extern "C" {
     pub(crate) fn text_node_num(t: f64) -> Node;
     #[wasm_bindgen(js_namespace = document, js_name = createTextNode)]
     pub(crate) fn text_node_bool(t: bool) -> Node;
+    #[wasm_bindgen(js_namespace = document, js_name = createElement)]
+    pub(crate) fn create_element(tag: &str) -> Node;
 
     // dom manipulation ----------------
 
     #[wasm_bindgen(method, js_name = "before")]
     pub(crate) fn append_before(this: &UnsafeNode, insert: &JsValue);
+    #[wasm_bindgen(method, js_name = "after")]
+    pub(crate) fn append_after(this: &UnsafeNode, insert: &JsValue);
+    #[wasm_bindgen(method, js_name = "appendChild")]
+    pub(crate) fn append_child(this: &UnsafeNode, child: &JsValue);
     #[wasm_bindgen(method, js_name = "remove")]
     pub(crate) fn unmount(this: &UnsafeNode);
     #[wasm_bindgen(method, js_name = "replaceWith")]
     pub(crate) fn replace(this: &UnsafeNode, new: &JsValue);
+    #[wasm_bindgen(method, js_name = "cloneNode")]
+    pub(crate) fn clone_node(this: &UnsafeNode, deep: bool) -> Node;
+    #[wasm_bindgen(method, getter, js_name = "content")]
+    pub(crate) fn template_content(this: &UnsafeNode) -> JsValue;
 
     // `set_text` variants ----------------
 
@@ -251,6 +434,8 @@ extern "C" {
     pub(crate) fn set_attr_num(this: &UnsafeNode, a: &str, v: f64);
     #[wasm_bindgen(method, js_name = "setAttribute")]
     pub(crate) fn set_attr_bool(this: &UnsafeNode, a: &str, v: bool);
+    #[wasm_bindgen(method, js_name = "removeAttribute")]
+    pub(crate) fn remove_attr(this: &UnsafeNode, a: &str);
 
     // provided attribute setters ----------------
 
@@ -262,10 +447,6 @@ extern "C" {
     pub(crate) fn href(this: &UnsafeNode, value: &str);
     #[wasm_bindgen(method, setter, js_name = "style")]
     pub(crate) fn style(this: &UnsafeNode, value: &str);
-    #[wasm_bindgen(method, setter, js_name = "value")]
-    pub(crate) fn value(this: &UnsafeNode, value: &str);
-    #[wasm_bindgen(method, setter, js_name = "value")]
-    pub(crate) fn value_num(this: &UnsafeNode, value: f64);
 }
 
 pub(crate) fn obj(node: &Node) -> &UnsafeNode {
@@ -295,11 +476,22 @@ extern "C" {
     pub(crate) fn fragment_unmount(f: &Node);
     #[wasm_bindgen(js_name = "fragmentReplace")]
     pub(crate) fn fragment_replace(f: &Node, new: &JsValue);
+    #[wasm_bindgen(js_name = "firstNode")]
+    pub(crate) fn first_node(n: &JsValue) -> Node;
+
+    #[wasm_bindgen(js_name = "activeElement")]
+    pub(crate) fn active_element() -> JsValue;
+    #[wasm_bindgen(js_name = "restoreFocus")]
+    pub(crate) fn restore_focus(node: &JsValue);
 
     // provided attribute setters ----------------
 
     #[wasm_bindgen(js_name = "setChecked")]
     pub(crate) fn checked(node: &Node, value: bool);
+    #[wasm_bindgen(js_name = "setValue")]
+    pub(crate) fn value(node: &Node, value: &str);
+    #[wasm_bindgen(js_name = "setValue")]
+    pub(crate) fn value_num(node: &Node, value: f64);
 
     // ----------------
 
@@ -316,9 +508,13 @@ extern "C" {
 
     #[wasm_bindgen(js_name = "makeEventHandler")]
     pub(crate) fn make_event_handler(closure: *mut (), vcall: usize) -> JsValue;
+    #[wasm_bindgen(js_name = "preventDefault")]
+    pub(crate) fn prevent_default(handler: &JsValue) -> JsValue;
 
     #[wasm_bindgen(js_name = "checkEventHandler")]
     pub(crate) fn check_event_handler();
+    #[wasm_bindgen(js_name = "warnUnusedKey")]
+    pub(crate) fn warn_unused_key();
 }
 
 #[cfg(test)]
@@ -4,8 +4,10 @@
 
 //! Keyword handles for `{ ... }` expressions in the [`view!`](crate::view) macro.
 
-use crate::diff::{Eager, Ref, Static};
-use crate::list::{Bounded, List};
+use crate::branching::IfConst;
+use crate::diff::{Diff, Eager, Ref, Static};
+use crate::list::keyed::KeyedView;
+use crate::list::{Bounded, KeyedList, List, MemoList, Reversed};
 use crate::View;
 
 /// `{ for ... }`: turn an [`IntoIterator`] type into a [`View`].
@@ -20,11 +22,29 @@ use crate::View;
 /// }
 /// # ;
 /// ```
-pub const fn r#for<T>(iterator: T) -> List<T>
-where
-    T: IntoIterator,
-    T::Item: View,
-{
+///
+/// It also accepts a [`window`](crate::list::Windowed::window) into an iterator.
+///
+/// The keyword can be omitted for [`map`](Iterator::map) and
+/// [`filter_map`](Iterator::filter_map) iterators, which implement [`View`] directly.
+///
+/// `rev`, `keyed` and `memo` right after `for` are read as modifiers, unless followed
+/// by `.`, `?` or `(`, so variables and functions with those names can still be used.
+/// Bind a parenthesized expression to a variable first to use it with a modifier:
+///
+/// ```
+/// # use kobold::prelude::*;
+/// fn rev(items: &[u32]) -> impl Iterator<Item = impl View + '_> + '_ {
+///     items.iter().rev().map(|n| view! { <li>{ *n }</li> })
+/// }
+///
+/// fn countdown(items: &[u32]) -> impl View + '_ {
+///     // Calls the `rev` function above
+///     view! { <ul>{ for rev(items) }</ul> }
+/// }
+/// # fn main() {}
+/// ```
+pub const fn r#for<T>(iterator: T) -> List<T> {
     List::new(iterator)
 }
 
@@ -53,6 +73,129 @@ pub const fn for_bounded<T, const N: usize>(iterator: T) -> List<T, Bounded<N>>
     List::new_bounded(iterator)
 }
 
+/// `{ for rev ... }`: turn an [`IntoIterator`] type into a [`View`] rendering
+/// its items in reverse order, see [`List::reversed`].
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[component]
+/// fn chat(messages: &[String]) -> impl View + '_ {
+///     view! {
+///         // Newest messages on top
+///         <ul>
+///         { for rev messages.iter().map(|msg| view! { <li>{ ref msg }</li> }) }
+///         </ul>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Keyed lists don't need this, as they match products by key: reversing the iterator
+/// of a [`for keyed`](for_keyed) list with [`Iterator::rev`] will reuse all products.
+pub fn for_rev<T>(iterator: T) -> List<T, Reversed>
+where
+    T: IntoIterator,
+    T::Item: View,
+{
+    List::new(iterator).reversed()
+}
+
+/// `{ for keyed ... }`: turn an [`IntoIterator`] of `(key, view)` pairs into a [`View`].
+///
+/// Unlike the regular `for`, which updates items by their position, a keyed list matches
+/// items by their key: when items are added, removed or reordered, the existing DOM nodes
+/// are updated and moved instead of being rebuilt. Items with duplicate keys are skipped.
+///
+/// Iterating over a [`BTreeMap`](std::collections::BTreeMap) gives a stable order sorted
+/// by key. A [`HashMap`](std::collections::HashMap) can be used as well, but its iteration
+/// order is arbitrary and can change as entries are inserted, which the rendered order
+/// will follow. Collect and sort the entries first if the order matters.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// use std::collections::BTreeMap;
+///
+/// fn users(users: &BTreeMap<u32, String>) -> impl View + '_ {
+///     view! {
+///         <ul>
+///         { for keyed users.iter().map(|(id, name)| (*id, view! { <li>{ ref name }</li> })) }
+///         </ul>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Instead of a tuple, the key can also be set with the `key` attribute on the root element
+/// of the item view, see [`Keyed`](crate::list::keyed::Keyed):
+///
+/// ```
+/// # use kobold::prelude::*;
+/// # use std::collections::BTreeMap;
+/// fn users(users: &BTreeMap<u32, String>) -> impl View + '_ {
+///     view! {
+///         <ul>
+///         { for keyed users.iter().map(|(id, name)| view! { <li key={*id}>{ ref name }</li> }) }
+///         </ul>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// The attribute is only allowed when the view has a single root element:
+///
+/// ```compile_fail
+/// # use kobold::prelude::*;
+/// fn item(id: u32) -> impl View {
+///     view! { <dt key={id}>"Id"</dt><dd>{ id }</dd> }
+/// }
+/// # fn main() {}
+/// ```
+pub const fn for_keyed<T>(iterator: T) -> KeyedList<T>
+where
+    T: IntoIterator,
+    T::Item: KeyedView,
+{
+    KeyedList::new(iterator)
+}
+
+/// `{ for memo ... }`: turn an [`IntoIterator`] of `(guard, view)` pairs into a [`View`].
+///
+/// Items are updated by their position same as with the regular `for`, but each item keeps
+/// the memo of its `guard` and its view is only updated if the `guard` has changed, same as
+/// a [`fence`](crate::diff::fence). This can save a lot of work in long lists where only a
+/// few items change between renders.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// use kobold::diff::Ver;
+///
+/// struct Row {
+///     label: Ver<String>,
+/// }
+///
+/// #[component]
+/// fn rows(rows: &[Row]) -> impl View + '_ {
+///     view! {
+///         <table>
+///         {
+///             for memo rows.iter().map(|row| {
+///                 (&row.label, view! { <tr><td>{ ref row.label }</td></tr> })
+///             })
+///         }
+///         </table>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub const fn for_memo<T, D, V>(iterator: T) -> MemoList<T>
+where
+    T: IntoIterator<Item = (D, V)>,
+    D: Diff,
+    V: View,
+{
+    MemoList::new(iterator)
+}
+
 /// `{ ref ... }`: diff this value by its reference address.
 ///
 /// For strings this is both faster and more memory efficient (no allocations necessary),
@@ -92,9 +235,68 @@ pub const fn r#use<T>(value: T) -> Eager<T> {
 }
 
 /// `{ static ... }` disable diffing for `T` and never update its value in the DOM after the initial render.
+///
+/// In attribute position the value is set once when the view is built, no memo for it is kept
+/// in the product:
+///
+/// ```
+/// # use kobold::prelude::*;
+/// use std::mem::size_of;
+///
+/// fn product_size<V: View>(_: &V) -> usize {
+///     size_of::<V::Product>()
+/// }
+///
+/// let (url, label, done) = ("https://example.com", "Example", true);
+///
+/// let link = view! {
+///     <a.link href={ static url } class={ static "external" }>{ label }</a>
+/// };
+/// let plain = view! { <a.link>{ label }</a> };
+/// let checkbox = view! { <input type="checkbox" checked={ static done }> };
+///
+/// assert_eq!(product_size(&link), product_size(&plain));
+/// assert_eq!(product_size(&checkbox), size_of::<web_sys::Node>());
+///
+/// // Without `static` the `href` is diffed on updates, so its memo is kept
+/// let diffed = view! { <a.link href={ url }>{ label }</a> };
+///
+/// assert!(product_size(&diffed) > product_size(&link));
+/// ```
 pub const fn r#static<T>(value: T) -> Static<T> {
     Static(value)
 }
 
 /// `{ do ... }` is an alias for [`{ event!(...) }`](../macro.event.html)
 pub use crate::event as r#do;
+
+/// `{ if const COND { ... } }`: render the block only if `COND` is `true`, where `COND`
+/// is a constant known at compile time, such as a `const` item or a `cfg!` flag.
+///
+/// Unlike a runtime `if`, there is no branching when the view is rendered or updated.
+/// With a `false` condition the block is never called and its product is zero-sized,
+/// so nothing is added to the DOM or stored in the product of the surrounding view:
+///
+/// ```
+/// # use kobold::prelude::*;
+/// const SHOW_VERSION: bool = false;
+///
+/// #[component]
+/// fn footer() -> impl View {
+///     view! {
+///         <footer>
+///             "Made with Kobold"
+///             { if const cfg!(debug_assertions) { view! { <p.debug>"Debug build"</p> } } }
+///             { if const SHOW_VERSION { view! { <p>"Version "{ env!("CARGO_PKG_VERSION") }</p> } } }
+///         </footer>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// With a `false` condition there is nothing in the DOM to anchor to, so `if const` blocks
+/// need to be placed inside an element rather than at the root of a view that can be
+/// swapped by a runtime branch. There is no `else`, use a runtime `if` for that.
+pub const fn if_const<const B: bool, F>(render: F) -> IfConst<B, F> {
+    IfConst(render)
+}
@@ -31,6 +31,44 @@
 //! type will be zero-sized, and its [`View::update`] method will be empty, making updates of static
 //! HTML literally zero-cost._
 //!
+//! An expression that is the only child of an element is appended to it directly, without an enclosing
+//! fragment, so the product of such a view is no larger than the element and the product of the expression:
+//!
+//! ```
+//! # use kobold::prelude::*;
+//! use std::mem::size_of;
+//!
+//! fn product_size<V: View>(_: &V) -> usize {
+//!     size_of::<V::Product>()
+//! }
+//!
+//! let count = 42_u32;
+//! let view = view! { <span.count>{ count }</span> };
+//!
+//! assert_eq!(
+//!     product_size(&view),
+//!     size_of::<web_sys::Node>() + size_of::<<u32 as View>::Product>()
+//! );
+//! ```
+//!
+//! Views rendered many times over, such as cells of a large grid, can be marked with the `#[template]`
+//! directive. The first instance constructs the element once and caches it, every instance after that
+//! is a deep clone of the cached element with expressions swapped in place. Unlike the
+//! [`static`](crate::keywords::static) keyword, which skips diffing of a single value, this is about many
+//! instances sharing the same structure:
+//!
+//! ```
+//! # use kobold::prelude::*;
+//! #[component]
+//! fn cell(x: usize, y: usize) -> impl View {
+//!     view! {
+//!         #[template]
+//!         <td.cell><b>{ x }", "{ y }</b></td>
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
 //! ### Hello World!
 //!
 //! Components in **Kobold** are created by annotating a _render function_ with a [`#[component]`](component) attribute.
@@ -191,6 +229,103 @@
 //!
 //! For more information about keywords visit the [`keywords` module documentation](keywords).
 //!
+//! ### Classes and ids
+//!
+//! Elements can use CSS-like shorthands for `class` and `id` attributes, which need to come before
+//! all other attributes. All literal class names are merged into a single `className` at compile time,
+//! so `<div.card.active>` is built with `class="card active"` without any runtime cost:
+//!
+//! ```
+//! # use kobold::prelude::*;
+//! #[component]
+//! fn card(selected: bool) -> impl View {
+//!     view! {
+//!         <div.card.active #main class={class!("selected" if selected)}>
+//!             "Card"
+//!         </div>
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Dynamic classes, either in `.{...}` shorthands or the `class={...}` attribute, are added on top of
+//! the static prefix and only ever add or remove their own class, so they never override the literal
+//! class names regardless of the order they are defined in.
+//!
+//! A shorthand class followed by `?={condition}` is conditional, `<div.card.active?={selected}>` is
+//! the same as `<div.card class={class!("active" if selected)}>`. The condition only applies to the class
+//! right before it, other literal classes are still set once when the element is built:
+//!
+//! ```
+//! # use kobold::prelude::*;
+//! #[component]
+//! fn card(selected: bool, urgent: bool) -> impl View {
+//!     view! {
+//!         <div.card.selected?={selected}.urgent?={urgent}>"Card"</div>
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Each [`class!`] toggles a single class, so class names with whitespace are rejected at compile time:
+//!
+//! ```compile_fail
+//! # use kobold::prelude::*;
+//! #[component]
+//! fn card(selected: bool) -> impl View {
+//!     view! {
+//!         // Error: Class name can't contain whitespace
+//!         <div class={class!("active selected" if selected)}>"Card"</div>
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ### Scoped styles
+//!
+//! A `<style>` block at the root of a [`view!`] is removed from the DOM and its CSS is scoped to the
+//! other root elements of that view. The macro tags those elements with a `data-k-*` attribute derived
+//! from a hash of the stylesheet, and wraps the CSS in an `@scope` rule for that attribute, so the same
+//! stylesheet always gets the same id. The stylesheet is added to `document.head` once when the Wasm
+//! module is loaded, rendering the component doesn't touch it:
+//!
+//! ```
+//! # use kobold::prelude::*;
+//! #[component]
+//! fn card(title: &str) -> impl View + '_ {
+//!     view! {
+//!         <style>
+//!             ":scope { border: 1px solid gray; }"
+//!             "h2 { color: tomato; }"
+//!         </style>
+//!         <div.card>
+//!             <h2>{ title }</h2>
+//!         </div>
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! With the `ssr` feature `kobold::ssr::render_to_string` writes the stylesheet inline
+//! with the same id, once per render, so it isn't inserted again on the client.
+//!
+//! Use `:scope` to target the root elements themselves. The scope covers the whole subtree, including
+//! any child components rendered inside it. Styles are static: expressions inside `<style>` are rejected
+//! at compile time, use the `style` attribute for dynamic values:
+//!
+//! ```compile_fail
+//! # use kobold::prelude::*;
+//! #[component]
+//! fn card(color: &str) -> impl View + '_ {
+//!     view! {
+//!         // Error: Scoped styles must be static, use the style attribute for dynamic values
+//!         <style>{ color }</style>
+//!         <div.card>"Card"</div>
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
 //! ### Borrowed Values
 //!
 //! [`View`] types are truly transient and only need to live for the duration of the initial render,
@@ -211,6 +346,58 @@
 //! # fn main() {}
 //! ```
 //!
+//! ### Passing State to Child Components
+//!
+//! The same applies to state owned by a [`stateful`](stateful::stateful) view. Child components can
+//! borrow fields of the state directly, or take the whole `&Hook` if they need to bind event handlers
+//! to it. Since the borrows only need to last for the duration of the render, the lifetime of
+//! the returned `impl View` is simply tied to the borrowed parameters:
+//!
+//! ```
+//! use kobold::diff::fence;
+//! use kobold::prelude::*;
+//!
+//! struct Profile {
+//!     name: String,
+//!     visits: u32,
+//! }
+//!
+//! // Borrows a single field of the parent state
+//! #[component]
+//! fn name_tag(name: &str) -> impl View + '_ {
+//!     view! { <h1>{ ref name }</h1> }
+//! }
+//!
+//! // Takes the `&Hook` so it can mutate the parent state
+//! #[component]
+//! fn visit_button(profile: &Hook<Profile>) -> impl View + '_ {
+//!     let onclick = profile.bind(|profile, _| profile.visits += 1);
+//!
+//!     view! { <button {onclick}>"Visit"</button> }
+//! }
+//!
+//! // Only re-rendered when `visits` has changed
+//! #[component]
+//! fn visits<'a>(visits: u32, name: &'a str) -> impl View + 'a {
+//!     fence(visits, move || view! {
+//!         <p>{ ref name }" has "{ visits }" visits"</p>
+//!     })
+//! }
+//!
+//! #[component]
+//! fn profile() -> impl View {
+//!     stateful(
+//!         || Profile { name: "Kobold".into(), visits: 0 },
+//!         |profile| view! {
+//!             <!name_tag name={&profile.name}>
+//!             <!visit_button {profile}>
+//!             <!visits visits={profile.visits} name={&profile.name}>
+//!         },
+//!     )
+//! }
+//! # fn main() {}
+//! ```
+//!
 //! ### Components with Children
 //!
 //! If you wish to capture children from parent [`view!`](view) invocation, simply change
@@ -255,6 +442,17 @@
 //! }
 //! ```
 //!
+//! ### Threading
+//!
+//! **Kobold** is single-threaded: views, products and [`stateful`](stateful::stateful) state use
+//! [`Rc`](std::rc::Rc) and live on the thread that owns the DOM, which in a browser is always the
+//! main thread. Builds with the `atomics` target feature are supported, but none of the **Kobold**
+//! types can be sent to other threads (web workers), which can only exchange plain data with the
+//! main thread.
+//!
+//! The optional `rlsf` allocator is not thread-safe and will fail to compile with `atomics`
+//! enabled, the default Rust allocator is used in multi-threaded builds instead.
+//!
 //! ## More Examples
 //!
 //! To run **Kobold** you'll need to install [`trunk`](https://trunkrs.dev/):
@@ -292,6 +490,34 @@
 /// # fn main() {}
 /// ```
 ///
+/// ## Spreading props
+///
+/// Every component has a `props()` function in its module returning a builder with a method
+/// for each parameter. A prepared builder can be spread onto the component with `{..props}`,
+/// which needs to come before all other properties. Properties set after the spread override
+/// the spread values, and optional parameters missing from both use their defaults:
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[component(size?: 16)]
+/// fn icon(name: &'static str, size: u32) -> impl View {
+///     view! { <i.icon data-name={name} data-size={size}></i> }
+/// }
+///
+/// #[component]
+/// fn toolbar() -> impl View {
+///     let save = icon::props().name("save").size(24);
+///
+///     view! {
+///         <nav>
+///             <!icon {..save}>
+///             <!icon {..icon::props().name("open")} size={32}>
+///         </nav>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ## Flags
 ///
 /// The `#[component]` attribute accepts a few optional flags using syntax: `#[component(<flag>)]`.
@@ -388,6 +614,62 @@
 ///
 /// For more details visit the [`branching` module documentation](branching).
 ///
+/// ### Memoize on parameters: `#[component(memo)]`
+///
+/// Wraps the body of the component in a [`fence`](crate::diff::fence) guarded by all of
+/// its parameters, skipping updates entirely unless at least one of them has changed.
+/// All parameters must implement [`Diff`](crate::diff::Diff), parameters that don't can be
+/// excluded from the guard with the `#[memo(skip)]` marker.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[component(memo)]
+/// fn user_row<'a>(id: u32, name: &'a str, #[memo(skip)] tag: &'a [&'a str]) -> impl View + 'a {
+///     // Only re-rendered when `id` or `name` have changed, changes to `tag` alone are ignored
+///     view! {
+///         <tr>
+///             <td>{ id }</td>
+///             <td>{ name }</td>
+///             <td>{ for tag.iter().copied() }</td>
+///         </tr>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### Fallible components: `#[component(fallible)]`
+///
+/// Allows the component to return a `Result<impl View, E>`, rendering the error when `Err` is returned.
+/// Available syntax:
+///
+/// * `#[component(fallible)]`: render the error in a `<pre>` element, `E` must implement [`Display`](std::fmt::Display).
+/// * `#[component(fallible: my_error)]`: render the error with a `my_error(err: E) -> impl View` function.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// use std::num::ParseIntError;
+///
+/// #[component(fallible)]
+/// fn double(input: &str) -> Result<impl View, ParseIntError> {
+///     // Renders `<pre>invalid digit found in string</pre>` on error
+///     let n: i32 = input.parse()?;
+///
+///     Ok(view! { <p>{ n * 2 }</p> })
+/// }
+///
+/// fn error(err: String) -> impl View {
+///     view! { <p.error>"Error: "{ err }</p> }
+/// }
+///
+/// #[component(fallible: error)]
+/// fn login(user: Option<&str>) -> Result<impl View, String> {
+///     let user = user.ok_or("Not logged in")?;
+///
+///     Ok(view! { <p>"Welcome, "{ user.to_owned() }</p> })
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ### Accept children: `#[component(children)]`
 ///
 /// Turns the component into a component that accepts children. Available syntax:
@@ -409,6 +691,13 @@ use wasm_bindgen::JsCast;
 #[global_allocator]
 static A: rlsf::SmallGlobalTlsf = rlsf::SmallGlobalTlsf::new();
 
+#[cfg(all(target_arch = "wasm32", feature = "rlsf", target_feature = "atomics"))]
+compile_error!(
+    "The `rlsf` allocator is single-threaded and can't be used with the `atomics` target feature, \
+    disable the `rlsf` feature of `kobold` to use the default allocator instead."
+);
+
+pub mod any;
 pub mod attribute;
 pub mod branching;
 pub mod diff;
@@ -418,12 +707,20 @@ pub mod internal;
 pub mod keywords;
 pub mod list;
 pub mod maybe;
-
-mod value;
+pub mod value;
 
 #[cfg(feature = "stateful")]
 pub mod stateful;
 
+#[cfg(feature = "stateful")]
+pub mod net;
+
+#[cfg(feature = "ssr")]
+pub mod ssr;
+
+#[cfg(feature = "test")]
+pub mod test;
+
 use internal::{In, Out};
 
 /// The prelude module with most commonly used types.
@@ -433,15 +730,17 @@ use internal::{In, Out};
 /// use kobold::prelude::*;
 /// ```
 pub mod prelude {
-    pub use crate::event::{Event, KeyboardEvent, MouseEvent};
-    pub use crate::{bind, class, event};
+    pub use crate::branching::{Branch2, Branch3, Empty};
+    pub use crate::event::{DragEvent, Event, KeyboardEvent, MouseEvent, UiEvent, WheelEvent};
+    pub use crate::{bind, branch, class, event};
     pub use crate::{component, view, View};
 
     #[cfg(feature = "stateful")]
-    pub use crate::stateful::{stateful, Hook, IntoState, Signal, Then};
+    pub use crate::stateful::{stateful, stateful_async, Hook, IntoState, Signal, Then};
 }
 
-use dom::Mountable;
+use diff::Diff;
+use dom::{Anchor, Mountable};
 
 /// Crate re-exports for the [`view!`](view) macro internals
 pub mod reexport {
@@ -461,6 +760,10 @@ pub trait View {
     /// Update the product and apply changes to the DOM if necessary.
     fn update(self, p: &mut Self::Product);
 
+    /// Render this view as HTML into the `out` string, see the [`ssr`] module.
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String);
+
     /// Once this view is built, do something once.
     fn on_mount<F>(self, handler: F) -> OnMount<Self, F>
     where
@@ -485,6 +788,106 @@ pub trait View {
             handler,
         }
     }
+
+    /// Focus the element of this view once it's built. The view must render an
+    /// element that can be focused, such as an `<input>` or a `<button>`.
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    ///
+    /// #[component]
+    /// fn search() -> impl View {
+    ///     view! { <input type="search"> }.focus_on_mount()
+    /// }
+    /// # fn main() {}
+    /// ```
+    fn focus_on_mount(self) -> FocusOnMount<Self>
+    where
+        Self: Sized,
+    {
+        FocusOnMount(self)
+    }
+
+    /// Scroll the element of this view into view whenever the value of `cond` changes,
+    /// but not when the view is first built.
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    ///
+    /// #[component]
+    /// fn message(text: &str, selected: bool) -> impl View + '_ {
+    ///     // Scrolls to the message once it's selected, or unselected
+    ///     view! { <p.message>{ text }</p> }.scroll_into_view_on(selected)
+    /// }
+    /// # fn main() {}
+    /// ```
+    fn scroll_into_view_on<D>(self, cond: D) -> ScrollIntoView<Self, D>
+    where
+        D: Diff,
+        Self: Sized,
+    {
+        ScrollIntoView { view: self, cond }
+    }
+
+    /// Wrap this view in a new `tag` element with the given `class`, an empty `class`
+    /// leaves the attribute out. Both are only used on build, updates only reach the
+    /// wrapped view.
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    ///
+    /// #[component(children)]
+    /// fn bordered(children: impl View) -> impl View {
+    ///     // Same as `view! { <div.card>{ children }</div> }`
+    ///     children.wrapped_in("div", "card")
+    /// }
+    /// # fn main() {}
+    /// ```
+    fn wrapped_in(self, tag: &'static str, class: &'static str) -> dom::Wrapped<Self>
+    where
+        Self: Sized,
+    {
+        dom::Wrapped::new(self, tag, class)
+    }
+
+    /// Run the `cleanup` closure when the product of this view is dropped, which happens
+    /// when the view is removed from the tree, e.g. when a branch switches to a different
+    /// variant, or when a [`Mount`] handle is dropped.
+    ///
+    /// This is meant for releasing Rust-side resources tied to the lifetime of the
+    /// product such as observers or timers, as opposed to DOM changes. Cleanup runs for
+    /// nested views as well since the products own each other. Only the closure passed in
+    /// the initial render is kept, those passed on updates are ignored.
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// use kobold::prelude::*;
+    ///
+    /// #[component]
+    /// fn subscriber(subscribers: &Rc<Cell<u32>>) -> impl View + '_ {
+    ///     let count = subscribers.clone();
+    ///     let subscribers = subscribers.clone();
+    ///
+    ///     view! {
+    ///         <p>"Subscribed!"</p>
+    ///     }
+    ///     .on_mount(move |_| count.set(count.get() + 1))
+    ///     .on_cleanup(move || subscribers.set(subscribers.get() - 1))
+    /// }
+    /// # fn main() {}
+    /// ```
+    fn on_cleanup<F>(self, cleanup: F) -> OnCleanup<Self, F>
+    where
+        F: FnOnce() + 'static,
+        Self: Sized,
+    {
+        OnCleanup {
+            view: self,
+            cleanup,
+        }
+    }
 }
 
 pub struct OnMount<V, F> {
@@ -510,6 +913,11 @@ where
     fn update(self, p: &mut Self::Product) {
         self.view.update(p);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.view.render_html(out);
+    }
 }
 
 pub struct OnRender<V, F> {
@@ -537,9 +945,159 @@ where
 
         (self.handler)(p.js().unchecked_ref());
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.view.render_html(out);
+    }
+}
+
+/// Smart [`View`] focusing its element on build, see [`View::focus_on_mount`].
+#[repr(transparent)]
+pub struct FocusOnMount<V>(V);
+
+impl<V: View> View for FocusOnMount<V> {
+    type Product = V::Product;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        let prod = self.0.build(p);
+
+        drop(prod.js().unchecked_ref::<web_sys::HtmlElement>().focus());
+
+        prod
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        self.0.update(p);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.0.render_html(out);
+    }
+}
+
+/// Smart [`View`] scrolling its element into view, see [`View::scroll_into_view_on`].
+pub struct ScrollIntoView<V, D> {
+    view: V,
+    cond: D,
+}
+
+/// Product of [`ScrollIntoView`], keeps the memo of the condition.
+pub struct ScrollProduct<P, M> {
+    product: P,
+    memo: M,
+}
+
+impl<V, D> View for ScrollIntoView<V, D>
+where
+    V: View,
+    D: Diff,
+{
+    type Product = ScrollProduct<V::Product, D::Memo>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        p.in_place(|p| unsafe {
+            init!(p.product @ self.view.build(p));
+            init!(p.memo = self.cond.into_memo());
+
+            Out::from_raw(p)
+        })
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        self.view.update(&mut p.product);
+
+        if self.cond.diff(&mut p.memo) {
+            p.product
+                .js()
+                .unchecked_ref::<web_sys::Element>()
+                .scroll_into_view();
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.view.render_html(out);
+    }
+}
+
+impl<P, M> Anchor for ScrollProduct<P, M>
+where
+    P: Mountable,
+    M: 'static,
+{
+    type Js = P::Js;
+    type Target = P;
+
+    fn anchor(&self) -> &P {
+        &self.product
+    }
+}
+
+pub struct OnCleanup<V, F> {
+    view: V,
+    cleanup: F,
+}
+
+/// Product of [`OnCleanup`], runs the cleanup closure on drop.
+pub struct CleanupProduct<P, F: FnOnce()> {
+    product: P,
+    cleanup: Option<F>,
+}
+
+impl<V, F> View for OnCleanup<V, F>
+where
+    V: View,
+    F: FnOnce() + 'static,
+{
+    type Product = CleanupProduct<V::Product, F>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        p.in_place(|p| unsafe {
+            init!(p.product @ self.view.build(p));
+            init!(p.cleanup = Some(self.cleanup));
+
+            Out::from_raw(p)
+        })
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        self.view.update(&mut p.product);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.view.render_html(out);
+    }
+}
+
+impl<P, F> Anchor for CleanupProduct<P, F>
+where
+    P: Mountable,
+    F: FnOnce() + 'static,
+{
+    type Js = P::Js;
+    type Target = P;
+
+    fn anchor(&self) -> &P {
+        &self.product
+    }
+}
+
+impl<P, F: FnOnce()> Drop for CleanupProduct<P, F> {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
 }
 
 /// Start the Kobold app by mounting given [`View`] in the document `body`.
+///
+/// There is no global runtime, every view owns its own state and event handlers,
+/// so independent apps don't interfere with each other. To host several apps on one
+/// page, for example widgets in a micro-frontend, use [`mount`] for each of them.
 pub fn start(view: impl View) {
     init_panic_hook();
 
@@ -555,24 +1113,149 @@ pub fn start(view: impl View) {
     internal::append_body(product.js());
 }
 
+/// Same as [`start`], but instead of throwing when the document has no `body` to
+/// mount the view in, it returns an error the host application can handle.
+///
+/// ```no_run
+/// # use kobold::prelude::*;
+/// use kobold::StartError;
+///
+/// fn embed() -> Result<(), StartError> {
+///     kobold::try_start(view! { <p>"Hello!"</p> })?;
+///
+///     // ...
+///     Ok(())
+/// }
+/// # fn main() {}
+/// ```
+///
+/// There is no global state to initialize, so calling this more than once is not
+/// an error: each call mounts another independent view, as [`start`] does.
+pub fn try_start(view: impl View) -> Result<(), StartError> {
+    let body = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or(StartError::NoDocument)?
+        .body()
+        .ok_or(StartError::NoBody)?;
+
+    init_panic_hook();
+
+    #[cfg(debug_assertions)]
+    internal::check_event_handler();
+
+    use std::mem::MaybeUninit;
+    use std::pin::pin;
+
+    let product = pin!(MaybeUninit::uninit());
+    let product = In::pinned(product, move |p| view.build(p));
+
+    drop(body.append_child(product.js().unchecked_ref()));
+
+    Ok(())
+}
+
+/// Error returned by [`try_start`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartError {
+    /// Not running in a browser window with a document, e.g. in a web worker.
+    NoDocument,
+    /// The document has no `body` element, e.g. when started from a script in the `head`
+    /// before the `body` is parsed.
+    NoBody,
+}
+
+impl std::fmt::Display for StartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            StartError::NoDocument => "Kobold needs a browser window with a document to start",
+            StartError::NoBody => "Kobold can't start without a `body` element in the document",
+        })
+    }
+}
+
+impl std::error::Error for StartError {}
+
+/// Mount given [`View`] as the last child of a DOM `node`, returning a [`Mount`]
+/// handle that can be used to update it.
+///
+/// Unlike [`start`] this can be called any number of times, each mount is independent
+/// of any others, which makes it possible to embed Kobold views in a page that is
+/// otherwise rendered by other means.
+///
+/// ```
+/// use kobold::prelude::*;
+///
+/// #[component]
+/// fn counter(count: u32) -> impl View {
+///     view! { <p>"Count: "{ count }</p> }
+/// }
+///
+/// fn island(node: &web_sys::Node) {
+///     let mut island = kobold::mount(node, view! { <!counter count={0}> });
+///
+///     // The view must be of the same type
+///     island.update(view! { <!counter count={1}> });
+///
+///     // Remove the view from the DOM, same as dropping the handle
+///     island.unmount();
+/// }
+/// # fn main() {}
+/// ```
+pub fn mount<V: View>(node: &web_sys::Node, view: V) -> Mount<V::Product> {
+    init_panic_hook();
+
+    #[cfg(debug_assertions)]
+    internal::check_event_handler();
+
+    let product = In::boxed(move |p| view.build(p));
+
+    drop(node.append_child(product.js().unchecked_ref()));
+
+    Mount { product }
+}
+
+/// Handle to a [`View`] mounted in the DOM, see [`mount`].
+///
+/// The view is unmounted from the DOM when the handle is dropped.
+pub struct Mount<P: Mountable> {
+    product: Box<P>,
+}
+
+impl<P: Mountable> Mount<P> {
+    /// Update the mounted view, applying any changes to the DOM.
+    pub fn update<V>(&mut self, view: V)
+    where
+        V: View<Product = P>,
+    {
+        view.update(&mut self.product);
+    }
+
+    /// Remove the mounted view from the DOM.
+    pub fn unmount(self) {}
+}
+
+impl<P: Mountable> Drop for Mount<P> {
+    fn drop(&mut self) {
+        self.product.unmount();
+    }
+}
+
 fn init_panic_hook() {
     // Only enable console hook on debug builds
     #[cfg(debug_assertions)]
     {
-        use std::cell::Cell;
+        use std::sync::Once;
 
-        thread_local! {
-            static INIT: Cell<bool> = const { Cell::new(false) };
-        }
-        if !INIT.with(|init| init.get()) {
-            std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+        // The panic hook is global, so this needs to be set once
+        // per process rather than once per thread.
+        static INIT: Once = Once::new();
 
-            INIT.with(|init| init.set(true));
-        }
+        INIT.call_once(|| std::panic::set_hook(Box::new(console_error_panic_hook::hook)));
     }
 }
 
-/// Binds a closure to a given [`Hook`](stateful::Hook). In practice:
+/// Binds closures to a given [`Hook`](stateful::Hook), shadowing the name of the hook with a
+/// mutable reference to its state inside the closures. In practice:
 ///
 /// ```
 /// # use kobold::{bind, stateful::Hook};
@@ -598,15 +1281,92 @@ fn init_panic_hook() {
 /// # throwaway(decrement);
 /// # }
 /// ```
+///
+/// Closures that don't need the event can omit it entirely, while those that do can annotate
+/// its type. Just like with [`Hook::bind`](stateful::Hook::bind), closures can return
+/// [`Then`](stateful::Then) to control rendering:
+///
+/// ```
+/// use kobold::prelude::*;
+/// use web_sys::HtmlInputElement;
+///
+/// #[component]
+/// fn name_input() -> impl View {
+///     stateful(String::new, |name| {
+///         bind! { name:
+///             let clear = move || name.clear();
+///             let oninput = move |e: Event<HtmlInputElement>| *name = e.current_target().value();
+///             let shout = move || {
+///                 let changed = !name.is_empty();
+///
+///                 name.make_ascii_uppercase();
+///
+///                 Then::render_if(changed)
+///             };
+///         }
+///
+///         view! {
+///             <input {oninput} value={name.as_str()}>
+///             <button onclick={clear}>"Clear"</button>
+///             <button onclick={shout}>"Shout"</button>
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
 #[macro_export]
 macro_rules! bind {
-    ($hook:ident: $(let $v:ident = move |$e:tt $(: $e_ty:ty)?| $body:expr;)+) => {
-        $(
-            let $v = $hook.bind(move |$hook, $e $(: $e_ty)*| $body);
-        )*
+    ($hook:ident:) => {};
+    ($hook:ident: let $v:ident = move || $body:expr; $($rest:tt)*) => {
+        let $v = $hook.bind(move |$hook, _| $body);
+
+        $crate::bind!($hook: $($rest)*);
+    };
+    ($hook:ident: let $v:ident = move |$e:tt $(: $e_ty:ty)?| $body:expr; $($rest:tt)*) => {
+        let $v = $hook.bind(move |$hook, $e $(: $e_ty)*| $body);
+
+        $crate::bind!($hook: $($rest)*);
     };
 }
 
+/// Binds an event handler to a [`Hook`](stateful::Hook), usually invoked through the
+/// [`do`](keywords::do) keyword inside the [`view!`](view) macro.
+///
+/// The handler gets a mutable reference to the state shadowing the name of the hook.
+/// Whether the view is rendered afterwards depends on what the handler returns, see
+/// [`ShouldRender`](stateful::ShouldRender):
+///
+/// * Handlers that return nothing, such as `do *count += 1` or `do state.name.push('!')`,
+///   always render. Any mutation made through the reference is therefore always visible.
+/// * Handlers returning [`Then`](stateful::Then) decide for themselves, `Then::Stop`
+///   mutates the state silently while `Then::StopPropagation` renders and keeps the event
+///   from bubbling up to handlers on parent elements, `Then::StopPropagationSilent` does
+///   the same without rendering.
+/// * The [`set`](stateful::set) and [`modify`](stateful::modify) helpers replace or update
+///   the state, or any of its fields, the same way [`Signal`](stateful::Signal) does.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[component]
+/// fn counter(init: u32) -> impl View {
+///     stateful(init, |count| {
+///         view! {
+///             <p>"Count: "{ count.get() }</p>
+///             // Always renders
+///             <button onclick={do *count += 1}>"Increment"</button>
+///             // Renders only if the count has changed
+///             <button onclick={do |count| {
+///                 let old = *count;
+///
+///                 *count = count.saturating_sub(1);
+///
+///                 Then::render_if(*count != old)
+///             }}>"Decrement"</button>
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
 #[macro_export]
 macro_rules! event {
     (move |$state:ident| $body:expr) => {
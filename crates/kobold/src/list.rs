@@ -6,14 +6,19 @@
 
 use std::marker::PhantomData;
 
+use crate::diff::{fence, Diff, Fence};
 use crate::internal::{In, Out};
 use crate::View;
 
 pub mod bounded;
+pub mod keyed;
 pub mod unbounded;
+pub mod window;
 
 use bounded::BoundedProduct;
-use unbounded::ListProduct;
+use keyed::{KeyedProduct, KeyedView};
+use unbounded::{ListProduct, Separated};
+use window::WindowProduct;
 
 /// Zero-sized marker making the [`List`] unbounded: it can grow to arbitrary
 /// size but will require memory allocation.
@@ -23,6 +28,10 @@ pub struct Unbounded;
 /// elements over the limit are ignored and no allocations are made.
 pub struct Bounded<const N: usize>;
 
+/// Zero-sized marker making the [`List`] unbounded and rendered in reverse order,
+/// see [`List::reversed`].
+pub struct Reversed;
+
 /// Wrapper type that implements `View` for iterators, created by the
 /// [`for`](crate::keywords::for) keyword.
 #[repr(transparent)]
@@ -36,6 +45,47 @@ impl<T> List<T> {
     pub const fn new_bounded<const N: usize>(item: T) -> List<T, Bounded<N>> {
         List(item, PhantomData)
     }
+
+    /// Render items in reverse order, the last item on top. This is used by
+    /// the [`for rev`](crate::keywords::for_rev) keyword.
+    ///
+    /// Unlike reversing the iterator itself, every item keeps its product
+    /// when new items are pushed, making this efficient for stack-like
+    /// interfaces such as a chat showing newest messages first.
+    pub fn reversed(self) -> List<T, Reversed> {
+        List(self.0, PhantomData)
+    }
+
+    /// Render a separator between consecutive items, there is no separator
+    /// before the first or after the last item:
+    ///
+    /// ```
+    /// # use kobold::prelude::*;
+    /// use kobold::list::List;
+    ///
+    /// #[component]
+    /// fn tags(tags: &[String]) -> impl View + '_ {
+    ///     let tags = tags.iter().map(|tag| view! { <b>{ tag }</b> });
+    ///
+    ///     view! {
+    ///         // Renders `<b>a</b>, <b>b</b>, <b>c</b>`
+    ///         <p>"Tags: "{ List::new(tags).separated_by(|| ", ") }</p>
+    ///     }
+    /// }
+    /// # fn main() {}
+    /// ```
+    ///
+    /// The separator closure is called for every separator that is built or updated.
+    /// Separators are products of the list like any item, so they are added and removed
+    /// together with the items following them.
+    pub fn separated_by<F, S>(self, separator: F) -> List<Separated<T::IntoIter, F>>
+    where
+        T: IntoIterator,
+        F: FnMut() -> S,
+        S: View,
+    {
+        List::new(Separated::new(self.0.into_iter(), separator))
+    }
 }
 
 impl<T> View for List<T>
@@ -52,6 +102,103 @@ where
     fn update(self, p: &mut Self::Product) {
         p.update(self.0.into_iter());
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        for view in self.0 {
+            view.render_html(out);
+        }
+    }
+}
+
+/// Mapped iterators can be used in the [`view!`](crate::view) macro directly,
+/// without the [`for`](crate::keywords::for) keyword:
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[component]
+/// fn list(items: &[String]) -> impl View + '_ {
+///     view! {
+///         <ul>{ items.iter().map(|item| view! { <li>{ item } }) }</ul>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Other iterators and collections still need to be wrapped with `for`.
+impl<I, F, V> View for std::iter::Map<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> V,
+    V: View,
+{
+    type Product = ListProduct<V::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        List::new(self).build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        List::new(self).update(p)
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        List::new(self).render_html(out)
+    }
+}
+
+/// Like [`Map`](std::iter::Map), items filtered out by the closure are skipped.
+impl<I, F, V> View for std::iter::FilterMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Option<V>,
+    V: View,
+{
+    type Product = ListProduct<V::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        List::new(self).build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        List::new(self).update(p)
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        List::new(self).render_html(out)
+    }
+}
+
+impl<T> View for List<T, Reversed>
+where
+    T: IntoIterator,
+    <T as IntoIterator>::Item: View,
+{
+    type Product = ListProduct<<T::Item as View>::Product, true>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        ListProduct::build(self.0.into_iter(), p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        p.update(self.0.into_iter());
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        // Rendering in reverse without collecting requires rendering
+        // each item separately and then prepending it to the output.
+        let start = out.len();
+        let mut item = String::new();
+
+        for view in self.0 {
+            item.clear();
+            view.render_html(&mut item);
+            out.insert_str(start, &item);
+        }
+    }
 }
 
 impl<T, const N: usize> View for List<T, Bounded<N>>
@@ -68,6 +215,180 @@ where
     fn update(self, p: &mut Self::Product) {
         p.update(self.0.into_iter());
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        for view in self.0 {
+            view.render_html(out);
+        }
+    }
+}
+
+/// Wrapper type that implements `View` for iterators of keyed items, created by the
+/// [`for keyed`](crate::keywords::for_keyed) keyword.
+#[repr(transparent)]
+pub struct KeyedList<T>(T);
+
+impl<T> KeyedList<T> {
+    pub const fn new(item: T) -> Self {
+        KeyedList(item)
+    }
+}
+
+impl<T> View for KeyedList<T>
+where
+    T: IntoIterator,
+    <T as IntoIterator>::Item: KeyedView,
+{
+    type Product =
+        KeyedProduct<<T::Item as KeyedView>::Key, <<T::Item as KeyedView>::View as View>::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        KeyedProduct::build(self.0.into_iter(), p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        p.update(self.0.into_iter());
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        for item in self.0 {
+            item.into_keyed().1.render_html(out);
+        }
+    }
+}
+
+/// Wrapper type that implements `View` for iterators of `(guard, view)` pairs, created by the
+/// [`for memo`](crate::keywords::for_memo) keyword.
+#[repr(transparent)]
+pub struct MemoList<T>(T);
+
+impl<T> MemoList<T> {
+    pub const fn new(item: T) -> Self {
+        MemoList(item)
+    }
+}
+
+impl<T, D, V> View for MemoList<T>
+where
+    T: IntoIterator<Item = (D, V)>,
+    D: Diff,
+    V: View,
+{
+    type Product = ListProduct<Fence<D::Memo, V::Product>>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        ListProduct::build(self.0.into_iter().map(fenced), p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        p.update(self.0.into_iter().map(fenced));
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        for (_, view) in self.0 {
+            view.render_html(out);
+        }
+    }
+}
+
+fn fenced<D, V>((guard, view): (D, V)) -> Fence<D, impl FnOnce() -> V>
+where
+    D: Diff,
+    V: View,
+{
+    fence(guard, move || view)
+}
+
+/// Extension trait adding the [`window`](Windowed::window) adapter to all iterators.
+pub trait Windowed: Iterator + Sized {
+    /// Render only `len` items of this iterator, starting at `offset`.
+    ///
+    /// Unlike `skip` and `take`, every rendered item keeps its product for as long as it
+    /// stays in the window, so moving the window only builds the items that have come into
+    /// view and removes the ones that have left it. This makes it a good fit for pagination
+    /// or fixed-size windows into large collections.
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    /// use kobold::list::Windowed;
+    ///
+    /// #[component]
+    /// fn page(rows: &[String], offset: usize) -> impl View + '_ {
+    ///     view! {
+    ///         <ul>
+    ///         {
+    ///             for rows.iter().map(|row| view! { <li>{ ref row }</li> }).window(offset, 20)
+    ///         }
+    ///         </ul>
+    ///     }
+    /// }
+    /// # fn main() {}
+    /// ```
+    fn window(self, offset: usize, len: usize) -> WindowList<Self> {
+        WindowList {
+            iter: self,
+            offset,
+            len,
+        }
+    }
+}
+
+impl<I: Iterator> Windowed for I {}
+
+/// Wrapper type that implements `View` for a window into an iterator,
+/// created by the [`window`](Windowed::window) adapter.
+pub struct WindowList<I> {
+    iter: I,
+    offset: usize,
+    len: usize,
+}
+
+impl<I> View for WindowList<I>
+where
+    I: Iterator,
+    I::Item: View,
+{
+    type Product = WindowProduct<<I::Item as View>::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        WindowProduct::build(self.iter, self.offset, self.len, p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        p.update(self.iter, self.offset, self.len);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        for view in self.iter.skip(self.offset).take(self.len) {
+            view.render_html(out);
+        }
+    }
+}
+
+/// Windows can be used with the [`for`](crate::keywords::for) keyword.
+impl<I> View for List<WindowList<I>>
+where
+    I: Iterator,
+    I::Item: View,
+{
+    type Product = WindowProduct<<I::Item as View>::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        self.0.build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        self.0.update(p);
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.0.render_html(out);
+    }
 }
 
 impl<V: View> View for Vec<V> {
@@ -80,6 +401,11 @@ impl<V: View> View for Vec<V> {
     fn update(self, p: &mut Self::Product) {
         List::new(self).update(p);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        List::new(self).render_html(out);
+    }
 }
 
 impl<'a, V> View for &'a [V]
@@ -95,6 +421,11 @@ where
     fn update(self, p: &mut Self::Product) {
         List::new(self).update(p)
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        List::new(self).render_html(out)
+    }
 }
 
 impl<V: View, const N: usize> View for [V; N] {
@@ -107,4 +438,9 @@ impl<V: View, const N: usize> View for [V; N] {
     fn update(self, p: &mut Self::Product) {
         List::new_bounded(self).update(p)
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        List::new_bounded::<N>(self).render_html(out)
+    }
 }
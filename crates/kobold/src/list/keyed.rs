@@ -0,0 +1,324 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Utilities for rendering keyed lists
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use web_sys::Node;
+
+use crate::dom::{Anchor, Fragment, FragmentBuilder};
+use crate::internal::{self, In, Out};
+use crate::{Mountable, View};
+
+/// An item of a [`KeyedList`](super::KeyedList): a [`View`] paired with a key
+/// that uniquely identifies it among its siblings.
+///
+/// This is implemented for `(K, V)` tuples, so mapping an iterator over the
+/// entries of a `HashMap` or a `BTreeMap` into pairs is all that's needed.
+pub trait KeyedView {
+    type Key: Hash + Eq + Clone + 'static;
+    type View: View;
+
+    fn into_keyed(self) -> (Self::Key, Self::View);
+}
+
+impl<K, V> KeyedView for (K, V)
+where
+    K: Hash + Eq + Clone + 'static,
+    V: View,
+{
+    type Key = K;
+    type View = V;
+
+    fn into_keyed(self) -> (K, V) {
+        self
+    }
+}
+
+/// A [`View`] paired with a key, created by setting the `key` attribute on the
+/// root element of a [`view!`](crate::view):
+///
+/// ```
+/// # use kobold::prelude::*;
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// fn users(users: &[User]) -> impl View + '_ {
+///     view! {
+///         <ul>
+///         { for keyed users.iter().map(|user| view! { <li key={user.id}>{ ref user.name }</li> }) }
+///         </ul>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Components accept the same reserved `key` attribute, which is never passed on as a prop:
+///
+/// ```
+/// # use kobold::prelude::*;
+/// # struct User { id: u32, name: String }
+/// #[component]
+/// fn row(name: &str) -> impl View + '_ {
+///     view! { <li>{ ref name }</li> }
+/// }
+///
+/// fn users(users: &[User]) -> impl View + '_ {
+///     view! {
+///         <ul>
+///         { for keyed users.iter().map(|user| view! { <!row key={user.id} name={&user.name}> }) }
+///         </ul>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// The key must be `Hash + Eq + Clone + 'static`, and it's evaluated before the rest of the view.
+/// Outside of a [`for keyed`](crate::keywords::for_keyed) list the key is ignored and the view
+/// renders as if it wasn't there, debug builds log a warning to the console when that happens.
+pub struct Keyed<K, V> {
+    key: K,
+    view: V,
+}
+
+/// Pair a `view` with a `key`, this is what the `key` attribute in the [`view!`](crate::view)
+/// macro expands to.
+pub const fn keyed<K, V>(key: K, view: V) -> Keyed<K, V> {
+    Keyed { key, view }
+}
+
+impl<K, V> KeyedView for Keyed<K, V>
+where
+    K: Hash + Eq + Clone + 'static,
+    V: View,
+{
+    type Key = K;
+    type View = V;
+
+    fn into_keyed(self) -> (K, V) {
+        (self.key, self.view)
+    }
+}
+
+impl<K, V> View for Keyed<K, V>
+where
+    V: View,
+{
+    type Product = V::Product;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        // Keyed lists build the view directly, so this is only reached for keys that are ignored
+        #[cfg(debug_assertions)]
+        internal::warn_unused_key();
+
+        self.view.build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        self.view.update(p)
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.view.render_html(out)
+    }
+}
+
+pub struct KeyedProduct<K, P: Mountable> {
+    list: Vec<(K, Box<P>)>,
+    prev: Vec<Option<(K, Box<P>)>>,
+    index: HashMap<K, usize>,
+    moves: Moves,
+    fragment: FragmentBuilder,
+}
+
+impl<K, P> KeyedProduct<K, P>
+where
+    K: Hash + Eq + Clone + 'static,
+    P: Mountable,
+{
+    pub fn build<I>(iter: I, p: In<Self>) -> Out<Self>
+    where
+        I: Iterator,
+        I::Item: KeyedView<Key = K>,
+        <I::Item as KeyedView>::View: View<Product = P>,
+    {
+        let mut list = p.put(KeyedProduct {
+            list: Vec::new(),
+            prev: Vec::new(),
+            index: HashMap::new(),
+            moves: Moves::default(),
+            fragment: FragmentBuilder::new(),
+        });
+
+        for (key, view) in iter.map(KeyedView::into_keyed) {
+            if list.index.contains_key(&key) {
+                continue;
+            }
+
+            let built = In::boxed(|p| view.build(p));
+
+            let idx = list.list.len();
+
+            list.fragment.append(built.js());
+            list.index.insert(key.clone(), idx);
+            list.list.push((key, built));
+        }
+
+        list
+    }
+
+    pub fn update<I>(&mut self, iter: I)
+    where
+        I: Iterator,
+        I::Item: KeyedView<Key = K>,
+        <I::Item as KeyedView>::View: View<Product = P>,
+    {
+        // Nodes that are moved around in the DOM lose focus, keep
+        // track of the focused element so it can be restored.
+        let focused = internal::active_element();
+
+        self.index.clear();
+        self.index.extend(
+            self.list
+                .iter()
+                .enumerate()
+                .map(|(idx, (key, _))| (key.clone(), idx)),
+        );
+        self.prev.extend(self.list.drain(..).map(Some));
+        self.moves.clear();
+
+        for (key, view) in iter.map(KeyedView::into_keyed) {
+            let Some(&idx) = self.index.get(&key) else {
+                let built = In::boxed(|p| view.build(p));
+
+                // Mark the key as taken so that duplicates are skipped
+                self.index.insert(key.clone(), usize::MAX);
+                self.moves.push(None);
+                self.list.push((key, built));
+
+                continue;
+            };
+
+            // Duplicate key, only the first occurrence is rendered
+            let Some((key, mut old)) = self.prev.get_mut(idx).and_then(Option::take) else {
+                continue;
+            };
+
+            view.update(&mut old);
+
+            self.moves.push(Some(idx));
+            self.list.push((key, old));
+        }
+
+        for (_, old) in self.prev.drain(..).flatten() {
+            old.unmount();
+        }
+
+        self.moves.find_stable();
+
+        // Walk the list backwards, products that kept their relative order
+        // stay in place, everything else is inserted before its next sibling.
+        let mut next = self.fragment.tail().clone();
+
+        for ((_, p), (source, stable)) in self.list.iter().zip(self.moves.iter()).rev() {
+            if !stable {
+                if source.is_some() {
+                    p.unmount();
+                }
+                internal::obj(&next).append_before(p.js());
+            }
+            next = internal::first_node(p.js());
+        }
+
+        internal::restore_focus(&focused);
+    }
+}
+
+/// Old positions of products in the new order, along with
+/// buffers used to find the ones that don't need to move.
+#[derive(Default)]
+struct Moves {
+    sources: Vec<Option<usize>>,
+    stable: Vec<bool>,
+    tails: Vec<usize>,
+    links: Vec<usize>,
+}
+
+impl Moves {
+    fn clear(&mut self) {
+        self.sources.clear();
+    }
+
+    fn push(&mut self, source: Option<usize>) {
+        self.sources.push(source);
+    }
+
+    fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Option<usize>, bool)> + ExactSizeIterator + '_ {
+        self.sources
+            .iter()
+            .copied()
+            .zip(self.stable.iter().copied())
+    }
+
+    /// Mark the longest increasing subsequence of old positions as stable,
+    /// this minimizes the number of DOM nodes that need to be moved.
+    fn find_stable(&mut self) {
+        let Moves {
+            sources,
+            stable,
+            tails,
+            links,
+        } = self;
+
+        stable.clear();
+        stable.resize(sources.len(), false);
+        links.clear();
+        links.resize(sources.len(), usize::MAX);
+        tails.clear();
+
+        for (idx, source) in sources.iter().enumerate() {
+            let Some(source) = *source else {
+                continue;
+            };
+
+            let pos = tails.partition_point(|&tail| sources[tail] < Some(source));
+
+            if pos > 0 {
+                links[idx] = tails[pos - 1];
+            }
+            if pos == tails.len() {
+                tails.push(idx);
+            } else {
+                tails[pos] = idx;
+            }
+        }
+
+        let mut idx = tails.last().copied().unwrap_or(usize::MAX);
+
+        while let Some(s) = stable.get_mut(idx) {
+            *s = true;
+            idx = links[idx];
+        }
+    }
+}
+
+impl<K, P> Anchor for KeyedProduct<K, P>
+where
+    K: 'static,
+    P: Mountable,
+{
+    type Js = Node;
+    type Target = Fragment;
+
+    fn anchor(&self) -> &Fragment {
+        &self.fragment
+    }
+}
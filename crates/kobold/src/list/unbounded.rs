@@ -4,19 +4,38 @@
 
 //! Utilities for rendering lists
 
+use wasm_bindgen::JsValue;
 use web_sys::Node;
 
+use crate::branching::Branch2;
 use crate::dom::{Anchor, Fragment, FragmentBuilder};
 use crate::internal::{In, Out};
 use crate::{Mountable, View};
 
-pub struct ListProduct<P: Mountable> {
+/// Product of an unbounded [`List`](crate::list::List), products are laid out in the
+/// DOM in reverse order if `REV` is `true`.
+pub struct ListProduct<P: Mountable, const REV: bool = false> {
     list: Vec<Box<P>>,
     mounted: usize,
     fragment: FragmentBuilder,
+    #[cfg(debug_assertions)]
+    stats: ListStats,
 }
 
-impl<P: Mountable> ListProduct<P> {
+/// What the last build or update of a [`ListProduct`] did to its items, see [`ListProduct::stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ListStats {
+    /// Items updated in place, including the remounted ones.
+    pub updated: usize,
+    /// Items that didn't have a product yet and were built.
+    pub built: usize,
+    /// Items that were previously unmounted and got mounted again.
+    pub remounted: usize,
+    /// Items unmounted from the DOM, their products are kept for reuse.
+    pub unmounted: usize,
+}
+
+impl<P: Mountable, const REV: bool> ListProduct<P, REV> {
     pub fn build<I>(iter: I, p: In<Self>) -> Out<Self>
     where
         I: Iterator,
@@ -26,12 +45,30 @@ impl<P: Mountable> ListProduct<P> {
             list: Vec::new(),
             mounted: 0,
             fragment: FragmentBuilder::new(),
+            #[cfg(debug_assertions)]
+            stats: ListStats::default(),
         });
 
         list.extend(iter);
+
+        #[cfg(debug_assertions)]
+        {
+            list.stats.built = list.list.len();
+        }
+
         list
     }
 
+    /// Counters for the last build or update of this list, only available in debug builds.
+    ///
+    /// Unbounded lists match items by their position, so a high number of updates and builds
+    /// when items are only inserted or removed at the front might be a good reason to switch
+    /// to a [`for keyed`](crate::keywords::for_keyed) list.
+    #[cfg(debug_assertions)]
+    pub fn stats(&self) -> ListStats {
+        self.stats
+    }
+
     pub fn update<I>(&mut self, mut iter: I)
     where
         I: Iterator,
@@ -39,6 +76,9 @@ impl<P: Mountable> ListProduct<P> {
     {
         let mut updated = 0;
 
+        #[cfg(debug_assertions)]
+        let (len, mounted) = (self.list.len(), self.mounted);
+
         while let Some(old) = self.list.get_mut(updated) {
             let Some(new) = iter.next() else {
                 break;
@@ -57,6 +97,16 @@ impl<P: Mountable> ListProduct<P> {
                 self.extend(iter);
             }
         }
+
+        #[cfg(debug_assertions)]
+        {
+            self.stats = ListStats {
+                updated,
+                built: self.list.len() - len,
+                remounted: updated.saturating_sub(mounted),
+                unmounted: mounted.saturating_sub(updated),
+            };
+        }
     }
 
     fn extend<I>(&mut self, iter: I)
@@ -64,10 +114,14 @@ impl<P: Mountable> ListProduct<P> {
         I: Iterator,
         I::Item: View<Product = P>,
     {
+        let fragment = &self.fragment;
+
+        // On build the fragment is still detached from the document, so all
+        // products are inserted into the DOM at once when the list is mounted.
         self.list.extend(iter.map(|view| {
             let built = In::boxed(|p| view.build(p));
 
-            self.fragment.append(built.js());
+            Self::insert(fragment, built.js());
 
             built
         }));
@@ -88,13 +142,23 @@ impl<P: Mountable> ListProduct<P> {
         debug_assert!(self.list.get(self.mounted..to).is_some());
 
         for p in unsafe { self.list.get_unchecked(self.mounted..to).iter() } {
-            self.fragment.append(p.js());
+            Self::insert(&self.fragment, p.js());
         }
         self.mounted = to;
     }
+
+    /// Mount a product after all other products, which is at the
+    /// start of the fragment when the list is reversed.
+    fn insert(fragment: &FragmentBuilder, child: &JsValue) {
+        if REV {
+            fragment.prepend(child);
+        } else {
+            fragment.append(child);
+        }
+    }
 }
 
-impl<P> Anchor for ListProduct<P>
+impl<P, const REV: bool> Anchor for ListProduct<P, REV>
 where
     P: Mountable,
 {
@@ -105,3 +169,53 @@ where
         &self.fragment
     }
 }
+
+/// Iterator interleaving items with separators, created by [`List::separated_by`](crate::list::List::separated_by).
+///
+/// Items are rendered as [`Branch2::A`] and separators as [`Branch2::B`], so in the
+/// [`ListProduct`] every item keeps an even index and every separator an odd one: items
+/// and separators are always updated in place, and when the list shrinks the trailing
+/// separator is unmounted together with the item after it.
+pub struct Separated<I: Iterator, F> {
+    iter: I,
+    separator: F,
+    next: Option<I::Item>,
+    started: bool,
+}
+
+impl<I: Iterator, F> Separated<I, F> {
+    pub(crate) fn new(iter: I, separator: F) -> Self {
+        Separated {
+            iter,
+            separator,
+            next: None,
+            started: false,
+        }
+    }
+}
+
+impl<I, F, S> Iterator for Separated<I, F>
+where
+    I: Iterator,
+    F: FnMut() -> S,
+{
+    type Item = Branch2<I::Item, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.next.take() {
+            return Some(Branch2::A(item));
+        }
+
+        let item = self.iter.next()?;
+
+        if !self.started {
+            self.started = true;
+
+            return Some(Branch2::A(item));
+        }
+
+        self.next = Some(item);
+
+        Some(Branch2::B((self.separator)()))
+    }
+}
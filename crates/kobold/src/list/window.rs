@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Utilities for rendering a window into a list
+
+use std::collections::VecDeque;
+
+use web_sys::Node;
+
+use crate::dom::{Anchor, Fragment, FragmentBuilder};
+use crate::internal::{self, In, Out};
+use crate::{Mountable, View};
+
+/// Product of a [`WindowList`](super::WindowList), each product is kept
+/// for the same position in the iterator as long as it stays in the window.
+pub struct WindowProduct<P: Mountable> {
+    list: VecDeque<Box<P>>,
+    offset: usize,
+    fragment: FragmentBuilder,
+}
+
+impl<P: Mountable> WindowProduct<P> {
+    pub fn build<I>(iter: I, offset: usize, len: usize, p: In<Self>) -> Out<Self>
+    where
+        I: Iterator,
+        I::Item: View<Product = P>,
+    {
+        let mut window = p.put(WindowProduct {
+            list: VecDeque::new(),
+            offset,
+            fragment: FragmentBuilder::new(),
+        });
+
+        for view in iter.skip(offset).take(len) {
+            window.push_back(view);
+        }
+
+        window
+    }
+
+    pub fn update<I>(&mut self, iter: I, offset: usize, len: usize)
+    where
+        I: Iterator,
+        I::Item: View<Product = P>,
+    {
+        // Drop products that are no longer in the window, leaving only
+        // the ones overlapping with it, starting at `start`.
+        let start = if offset >= self.offset {
+            let skip = (offset - self.offset).min(self.list.len());
+
+            for p in self.list.drain(..skip) {
+                p.unmount();
+            }
+
+            offset.max(self.offset)
+        } else {
+            let keep = len.saturating_sub(self.offset - offset);
+
+            self.truncate(keep);
+
+            match self.list.is_empty() {
+                true => offset,
+                false => self.offset,
+            }
+        };
+
+        let mut pos = 0;
+
+        for view in iter.skip(offset).take(len) {
+            if offset + pos < start {
+                // Products before the previous window are mounted before
+                // the first product that is still in the window.
+                let built = In::boxed(|p| view.build(p));
+                let next = internal::first_node(self.list[pos].js());
+
+                internal::obj(&next).append_before(built.js());
+
+                self.list.insert(pos, built);
+            } else if let Some(old) = self.list.get_mut(pos) {
+                view.update(old);
+            } else {
+                self.push_back(view);
+            }
+
+            pos += 1;
+        }
+
+        self.truncate(pos);
+        self.offset = offset;
+    }
+
+    fn push_back<V>(&mut self, view: V)
+    where
+        V: View<Product = P>,
+    {
+        let built = In::boxed(|p| view.build(p));
+
+        self.fragment.append(built.js());
+        self.list.push_back(built);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        for p in self.list.drain(len.min(self.list.len())..) {
+            p.unmount();
+        }
+    }
+}
+
+impl<P> Anchor for WindowProduct<P>
+where
+    P: Mountable,
+{
+    type Js = Node;
+    type Target = Fragment;
+
+    fn anchor(&self) -> &Fragment {
+        &self.fragment
+    }
+}
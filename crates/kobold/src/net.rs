@@ -0,0 +1,169 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Utilities for feeding network connections into state
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CloseEvent, MessageEvent};
+
+use crate::stateful::Signal;
+
+/// Open a [WebSocket](https://developer.mozilla.org/en-US/docs/Web/API/WebSocket) connection
+/// to `url`, calling `on_message` with every message received along with the `signal` so
+/// that messages can be fed into state.
+///
+/// The connection is closed when the returned [`Socket`] is dropped, returning it from
+/// [`once`](crate::stateful::Stateful::once) ties it to the lifetime of the component.
+/// Fails if `url` is not a valid WebSocket URL.
+///
+/// ```
+/// use kobold::net::websocket;
+/// use kobold::prelude::*;
+///
+/// #[component]
+/// fn feed() -> impl View {
+///     stateful(Vec::new, |messages: &Hook<Vec<String>>| {
+///         view! {
+///             <ul>
+///             { for messages.iter().map(|msg| view! { <li>{ ref msg }</li> }) }
+///             </ul>
+///         }
+///     })
+///     .once(|signal| {
+///         let socket = websocket("wss://example.com/feed", signal, |msg, signal| {
+///             if let Some(text) = msg.data().as_string() {
+///                 signal.update(move |messages| messages.push(text));
+///             }
+///         });
+///
+///         // Try to reconnect a second after the connection drops
+///         socket.ok().map(|socket| socket.reconnect(1000))
+///     })
+/// }
+/// # fn main() {}
+/// ```
+pub fn websocket<S, F>(url: &str, signal: Signal<S>, mut on_message: F) -> Result<Socket, JsValue>
+where
+    S: 'static,
+    F: FnMut(MessageEvent, &Signal<S>) + 'static,
+{
+    let inner = Rc::new_cyclic(|weak: &Weak<Connection>| {
+        let on_close = weak.clone();
+        let on_timeout = weak.clone();
+
+        Connection {
+            url: url.into(),
+            socket: RefCell::new(None),
+            reconnect: Cell::new(None),
+            timeout: Cell::new(None),
+            on_message: Closure::new(move |msg| on_message(msg, &signal)),
+            on_close: Closure::new(move |_: CloseEvent| {
+                if let Some(conn) = on_close.upgrade() {
+                    conn.schedule_reconnect();
+                }
+            }),
+            on_timeout: Closure::new(move || {
+                if let Some(conn) = on_timeout.upgrade() {
+                    conn.timeout.set(None);
+
+                    // The URL has been valid for the initial connection
+                    let _ = conn.connect();
+                }
+            }),
+        }
+    });
+
+    inner.connect()?;
+
+    Ok(Socket { inner })
+}
+
+/// Handle to a WebSocket connection opened with [`websocket`].
+///
+/// The connection is closed when this handle is dropped.
+#[must_use = "the connection is closed as soon as the `Socket` is dropped"]
+pub struct Socket {
+    inner: Rc<Connection>,
+}
+
+struct Connection {
+    url: String,
+    socket: RefCell<Option<web_sys::WebSocket>>,
+    reconnect: Cell<Option<u32>>,
+    timeout: Cell<Option<i32>>,
+    on_message: Closure<dyn FnMut(MessageEvent)>,
+    on_close: Closure<dyn FnMut(CloseEvent)>,
+    on_timeout: Closure<dyn FnMut()>,
+}
+
+impl Socket {
+    /// Reconnect `delay` milliseconds after the connection has been closed by the
+    /// server or dropped due to network errors.
+    pub fn reconnect(self, delay: u32) -> Self {
+        self.inner.reconnect.set(Some(delay));
+        self
+    }
+
+    /// Send a text message, fails if the connection isn't open.
+    pub fn send(&self, text: &str) -> Result<(), JsValue> {
+        match &*self.inner.socket.borrow() {
+            Some(socket) => socket.send_with_str(text),
+            None => Err(JsValue::NULL),
+        }
+    }
+
+    /// Send a binary message, fails if the connection isn't open.
+    pub fn send_bytes(&self, bytes: &[u8]) -> Result<(), JsValue> {
+        match &*self.inner.socket.borrow() {
+            Some(socket) => socket.send_with_u8_array(bytes),
+            None => Err(JsValue::NULL),
+        }
+    }
+}
+
+impl Connection {
+    fn connect(&self) -> Result<(), JsValue> {
+        let socket = web_sys::WebSocket::new(&self.url)?;
+
+        socket.set_onmessage(Some(self.on_message.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(self.on_close.as_ref().unchecked_ref()));
+
+        *self.socket.borrow_mut() = Some(socket);
+
+        Ok(())
+    }
+
+    fn schedule_reconnect(&self) {
+        let (Some(delay), Some(window)) = (self.reconnect.get(), web_sys::window()) else {
+            return;
+        };
+
+        let timeout = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+            self.on_timeout.as_ref().unchecked_ref(),
+            delay as i32,
+        );
+
+        self.timeout.set(timeout.ok());
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        if let (Some(timeout), Some(window)) = (self.inner.timeout.take(), web_sys::window()) {
+            window.clear_timeout_with_handle(timeout);
+        }
+
+        if let Some(socket) = self.inner.socket.take() {
+            // Detach handlers before the closures they point to are dropped
+            socket.set_onmessage(None);
+            socket.set_onclose(None);
+
+            let _ = socket.close();
+        }
+    }
+}
@@ -0,0 +1,171 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! # Server-side rendering
+//!
+//! With the `ssr` feature enabled every [`View`] can also be rendered to an HTML string
+//! without touching the DOM, which makes it possible to produce the initial markup of
+//! an app on the server:
+//!
+//! ```
+//! use kobold::prelude::*;
+//! use kobold::ssr::render_to_string;
+//!
+//! #[component]
+//! fn hello(name: &str) -> impl View + '_ {
+//!     view! {
+//!         <h1.greeting>"Hello "{ name }"!"</h1>
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let html = render_to_string(view! {
+//!     <!hello name="<Kobold>">
+//! });
+//!
+//! assert_eq!(html, r#"<h1 class="greeting">Hello &lt;Kobold&gt;!</h1>"#);
+//! # }
+//! ```
+//!
+//! Event listeners are skipped and stateful views are rendered with their initial state,
+//! any [`once`](crate::stateful::Stateful::once) handlers are never invoked.
+
+use std::cell::RefCell;
+use std::fmt::{self, Display, Write};
+
+use crate::View;
+
+thread_local! {
+    /// Ids of scoped styles already written by the current [`render_to_string`] call.
+    static STYLES: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Render a [`View`] to an HTML string.
+///
+/// Scoped styles are only written once, with the first view that uses them.
+pub fn render_to_string(view: impl View) -> String {
+    let mut out = String::new();
+
+    // Keep styles of an outer call in case this one is nested in a `render_html`
+    let outer = STYLES.with_borrow_mut(std::mem::take);
+
+    view.render_html(&mut out);
+
+    STYLES.set(outer);
+
+    out
+}
+
+/// Write a scoped `<style>` element with `id` to `out`, unless one with the same `id`
+/// has already been written. The `id` matches the element inserted on the client,
+/// which then won't insert it again.
+pub fn style(id: &'static str, css: &str, out: &mut String) {
+    let first = STYLES.with_borrow_mut(|styles| {
+        if styles.contains(&id) {
+            return false;
+        }
+        styles.push(id);
+        true
+    });
+
+    if first {
+        out.push_str("<style id=\"");
+        out.push_str(id);
+        out.push_str("\">");
+        out.push_str(css);
+        out.push_str("</style>");
+    }
+}
+
+/// Write `value` to `out`, escaping any characters that are special in HTML.
+pub fn escape(value: impl Display, out: &mut String) {
+    let _ = write!(Escape(out), "{value}");
+}
+
+/// Write an attribute `name` with a `value` to `out`, including the leading space.
+pub fn attribute(name: &str, value: impl Display, out: &mut String) {
+    out.push(' ');
+    out.push_str(name);
+    out.push_str("=\"");
+    escape(value, out);
+    out.push('"');
+}
+
+/// Write a single class name to the `class` attribute in `out`, skipping empty names.
+pub fn class(class: &str, out: &mut String) {
+    if !class.is_empty() {
+        out.push(' ');
+        escape(class, out);
+    }
+}
+
+struct Escape<'a>(&'a mut String);
+
+impl Write for Escape<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '&' => self.0.push_str("&amp;"),
+                '<' => self.0.push_str("&lt;"),
+                '>' => self.0.push_str("&gt;"),
+                '"' => self.0.push_str("&quot;"),
+                '\'' => self.0.push_str("&#39;"),
+                c => self.0.push(c),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escapes_text() {
+        let mut out = String::new();
+
+        escape("<b>\"Fish\" & 'Chips'</b>", &mut out);
+
+        assert_eq!(
+            out,
+            "&lt;b&gt;&quot;Fish&quot; &amp; &#39;Chips&#39;&lt;/b&gt;"
+        );
+    }
+
+    #[test]
+    fn renders_attributes() {
+        let mut out = String::new();
+
+        attribute("href", "/?a=1&b=2", &mut out);
+        attribute("width", 42, &mut out);
+
+        assert_eq!(out, r#" href="/?a=1&amp;b=2" width="42""#);
+    }
+
+    #[test]
+    fn skips_empty_classes() {
+        let mut out = String::new();
+
+        class("foo", &mut out);
+        class("", &mut out);
+        class("bar", &mut out);
+
+        assert_eq!(out, " foo bar");
+    }
+
+    #[test]
+    fn writes_styles_once() {
+        let mut out = String::new();
+
+        style("k-1", "a {}", &mut out);
+        style("k-2", "b {}", &mut out);
+        style("k-1", "a {}", &mut out);
+
+        assert_eq!(
+            out,
+            r#"<style id="k-1">a {}</style><style id="k-2">b {}</style>"#
+        );
+    }
+}
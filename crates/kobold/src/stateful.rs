@@ -11,13 +11,15 @@
 //! could ever do is render itself once. To get around this the [`stateful`] function can
 //! be used to create views that have ownership over some arbitrary mutable state.
 //!
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
+use std::future::Future;
 use std::mem::MaybeUninit;
 use std::rc::Rc;
 
 use wasm_bindgen::JsValue;
 use web_sys::Node;
 
+use crate::branching::Branch2;
 use crate::dom::Anchor;
 use crate::internal::{In, Out};
 use crate::{init, Mountable, View};
@@ -25,19 +27,51 @@ use crate::{init, Mountable, View};
 mod cell;
 mod hook;
 mod into_state;
+mod mutate;
 mod product;
+mod radio;
 mod should_render;
 
 use cell::WithCell;
 use product::{Product, ProductHandler};
 
-pub use hook::{Bound, Hook, Signal};
+pub use hook::{AnimationFrames, Bound, Hook, MappedSignal, Signal, Task};
 pub use into_state::IntoState;
+pub use mutate::{modify, set};
+pub use radio::{radio_group, Radio};
+
+/// Derive [`IntoState`](trait@IntoState) for a struct or an enum, using the value itself as state.
+///
+/// Like with closures, the state is only initialized once, new values passed in when
+/// the parent view updates are ignored.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[derive(IntoState)]
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// #[component]
+/// fn counter() -> impl View {
+///     stateful(Counter { count: 0 }, |counter| {
+///         view! {
+///             <p>"Count: "{ counter.count }</p>
+///             <button onclick={do counter.count += 1}>"+"</button>
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
+pub use kobold_macros::IntoState;
 pub use should_render::{ShouldRender, Then};
 
 #[repr(C)]
 struct Inner<S, P: ?Sized = dyn Product<S>> {
     state: WithCell<S>,
+    /// Set while a render is scheduled for the next animation frame,
+    /// see [`Signal::update_raf`].
+    frame: Cell<bool>,
     prod: UnsafeCell<P>,
 }
 
@@ -63,6 +97,14 @@ pub struct StatefulProduct<S> {
 ///
 /// // ...or a function with no parameters
 /// let vec_view = stateful(Vec::new, |counts: &Hook<Vec<i32>>| { "TODO" });
+///
+/// // Custom types can derive `IntoState` to be used directly
+/// #[derive(IntoState)]
+/// struct State {
+///     names: Vec<String>,
+/// }
+///
+/// let state_view = stateful(State { names: Vec::new() }, |state: &Hook<State>| { "TODO" });
 /// ```
 pub fn stateful<'a, S, F, V>(
     state: S,
@@ -82,6 +124,68 @@ where
     Stateful { state, render }
 }
 
+/// Create a stateful [`View`] with initial state loaded from a [`Future`]. The `pending` view
+/// is rendered until the future resolves, after which `render` takes over with the loaded state
+/// the same way it would with [`stateful`].
+///
+/// The future is aborted if the view is removed before it completes.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// # async fn fetch_todos() -> Vec<String> { Vec::new() }
+/// #[component]
+/// fn todos() -> impl View {
+///     stateful_async(
+///         fetch_todos(),
+///         || view! { <p>"Loading..."</p> },
+///         |todos: &Hook<Vec<String>>| {
+///             view! {
+///                 <ul>
+///                 { for todos.iter().map(|todo| view! { <li>{ ref todo }</li> }) }
+///                 </ul>
+///             }
+///         },
+///     )
+/// }
+/// # fn main() {}
+/// ```
+pub fn stateful_async<T, P, PV, F, V>(init: T, pending: P, render: F) -> impl View
+where
+    T: Future + 'static,
+    P: Fn() -> PV + 'static,
+    PV: View + 'static,
+    F: Fn(&'static Hook<T::Output>) -> V + 'static,
+    V: View + 'static,
+{
+    let render = Rc::new(render);
+
+    stateful(
+        || AsyncInit::Pending,
+        move |init: &Hook<AsyncInit<T::Output>>| match &**init {
+            AsyncInit::Pending => Branch2::A(pending()),
+            AsyncInit::Ready(state) => {
+                let render = render.clone();
+
+                // The state is only taken on build, updates of a view created
+                // from a closure never call it.
+                let state = move || state.take().expect("state already taken");
+
+                Branch2::B(stateful(state, move |hook| render(hook)))
+            }
+        },
+    )
+    .once(|signal| {
+        signal.async_update(init, |init, state| {
+            *init = AsyncInit::Ready(Cell::new(Some(state)));
+        })
+    })
+}
+
+enum AsyncInit<S> {
+    Pending,
+    Ready(Cell<Option<S>>),
+}
+
 impl<S, P> Inner<S, MaybeUninit<P>> {
     unsafe fn as_init(&self) -> &Inner<S, P> {
         &*(self as *const _ as *const Inner<S, P>)
@@ -120,6 +224,7 @@ where
     fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
         let inner = Rc::new(Inner {
             state: WithCell::new(self.state.init()),
+            frame: Cell::new(false),
             prod: UnsafeCell::new(MaybeUninit::uninit()),
         });
 
@@ -162,6 +267,17 @@ where
             }
         })
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        let inner = Inner {
+            state: WithCell::new(self.state.init()),
+            frame: Cell::new(false),
+            prod: UnsafeCell::new(product::Unmounted),
+        };
+
+        (self.render)(Hook::new(&inner)).render_html(out);
+    }
 }
 
 impl<S> Mountable for StatefulProduct<S>
@@ -234,9 +350,7 @@ where
     fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
         p.in_place(|p| unsafe {
             let product = init!(p.product @ self.with_state.build(p));
-            let signal = Signal {
-                weak: Rc::downgrade(&product.inner),
-            };
+            let signal = Signal::new(Rc::downgrade(&product.inner));
 
             init!(p._no_drop = (self.handler)(signal));
 
@@ -247,4 +361,9 @@ where
     fn update(self, p: &mut Self::Product) {
         self.with_state.update(&mut p.product);
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.with_state.render_html(out);
+    }
 }
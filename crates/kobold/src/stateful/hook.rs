@@ -2,15 +2,21 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::future::Future;
+use std::cell::{Cell, RefCell};
+use std::future::{poll_fn, Future};
 use std::marker::PhantomData;
 use std::mem::ManuallyDrop;
 use std::ops::Deref;
+use std::pin::pin;
 use std::rc::{Rc, Weak};
+use std::task::{Poll, Waker};
 
 use wasm_bindgen_futures::spawn_local;
 
-use crate::event::{EventCast, Listener};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+use crate::event::{self, EventCast, Listener, TargetValue};
 use crate::internal::{In, Out};
 use crate::stateful::{Inner, ShouldRender};
 use crate::View;
@@ -18,8 +24,9 @@ use crate::View;
 /// A hook into some state `S`. A reference to `Hook` is obtained by using the [`stateful`](crate::stateful::stateful)
 /// function.
 ///
-/// Hook can be read from though its `Deref` implementation, and it allows for mutations either by [`bind`ing](Hook::bind)
-/// closures to it.
+/// Hook can be read from through its `Deref` implementation, and it allows for mutations by [`bind`ing](Hook::bind)
+/// closures to it. Inside of those closures the state can be replaced or updated using the [`set`](crate::stateful::set)
+/// and [`modify`](crate::stateful::modify) helpers, while [`Signal`] provides the same for code running outside of event handlers.
 #[repr(transparent)]
 pub struct Hook<S> {
     inner: Inner<S>,
@@ -27,10 +34,20 @@ pub struct Hook<S> {
 
 #[repr(transparent)]
 pub struct Signal<S> {
-    pub(super) weak: Weak<Inner<S>>,
+    weak: Weak<Inner<S>>,
 }
 
+/// Mutator passed to a projection, returning whether the view should render.
+type Mutator<'a, S> = dyn FnMut(&mut S) -> bool + 'a;
+
+/// Projection receiving a mutator, and whether the render should be scheduled.
+type Projection<T> = Rc<dyn Fn(&mut Mutator<T>, bool)>;
+
 impl<S> Signal<S> {
+    pub(super) fn new(weak: Weak<Inner<S>>) -> Self {
+        Signal { weak }
+    }
+
     /// Update the state behind this `Signal`.
     ///
     /// ```
@@ -62,6 +79,32 @@ impl<S> Signal<S> {
         }
     }
 
+    /// Same as [`update`](Signal::update), but instead of rendering right away the render
+    /// is scheduled for the next animation frame. The state is updated immediately, while
+    /// any number of updates made before the frame result in a single render of the latest state.
+    ///
+    /// This is meant for high-frequency updates, such as sensor readings or messages from
+    /// a socket, that can arrive faster than the browser can paint them:
+    ///
+    /// ```
+    /// # use kobold::prelude::*;
+    /// fn on_reading(readings: Signal<Vec<f64>>, reading: f64) {
+    ///     readings.update_raf(|readings| readings.push(reading));
+    /// }
+    /// ```
+    pub fn update_raf<F, O>(&self, mutator: F)
+    where
+        S: 'static,
+        F: FnOnce(&mut S) -> O,
+        O: ShouldRender,
+    {
+        if let Some(inner) = self.weak.upgrade() {
+            if inner.state.with(mutator).should_render() {
+                request_frame(&inner);
+            }
+        }
+    }
+
     /// Same as [`update`](Signal::update), but it never renders updates.
     pub fn update_silent<F>(&self, mutator: F)
     where
@@ -73,9 +116,247 @@ impl<S> Signal<S> {
     }
 
     /// Replace the entire state with a new value and trigger an update.
+    ///
+    /// Inside of event handlers use [`stateful::set`](crate::stateful::set) instead.
     pub fn set(&self, val: S) {
         self.update(move |s| *s = val);
     }
+
+    /// Create a [`MappedSignal`] to a part of the state, such as one of its fields, so that it
+    /// can be handed to code that doesn't need to know about the rest of it. Updates through
+    /// the returned signal render the same view as updates through this one.
+    ///
+    /// ```
+    /// # use kobold::prelude::*;
+    /// use kobold::stateful::MappedSignal;
+    ///
+    /// struct User {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// fn rename(name: MappedSignal<String>) {
+    ///     name.set("Alice".into());
+    /// }
+    ///
+    /// fn example(user: Signal<User>) {
+    ///     rename(user.map(|user| &mut user.name));
+    /// }
+    /// ```
+    pub fn map<T, F>(&self, project: F) -> MappedSignal<T>
+    where
+        S: 'static,
+        F: Fn(&mut S) -> &mut T + 'static,
+    {
+        let weak = self.weak.clone();
+
+        MappedSignal {
+            project: Rc::new(move |mutator: &mut Mutator<T>, frame: bool| {
+                let Some(inner) = weak.upgrade() else {
+                    return;
+                };
+
+                if inner.state.with(|state| mutator(project(state))) {
+                    match frame {
+                        true => request_frame(&inner),
+                        false => inner.update(),
+                    }
+                }
+            }),
+        }
+    }
+
+    /// Spawn a `future` and once it completes [`update`](Signal::update) the state
+    /// with its output using the `mutator` closure.
+    ///
+    /// State is never borrowed while the `future` is pending. Dropping the returned
+    /// [`Task`] aborts the `future`, returning it from [`once`](crate::stateful::Stateful::once)
+    /// ties it to the lifetime of the component:
+    ///
+    /// ```
+    /// # use kobold::prelude::*;
+    /// # async fn fetch_user() -> String { String::new() }
+    /// #[component]
+    /// fn user() -> impl View {
+    ///     stateful(|| None, |name: &Hook<Option<String>>| {
+    ///         view! { <p>{ name.as_deref().unwrap_or("Loading...") }</p> }
+    ///     })
+    ///     .once(|signal| {
+    ///         // Request is aborted if the component is removed before it completes
+    ///         signal.async_update(fetch_user(), |name, user| *name = Some(user))
+    ///     })
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn async_update<T, F, O>(&self, future: T, mutator: F) -> Task
+    where
+        S: 'static,
+        T: Future + 'static,
+        F: FnOnce(&mut S, T::Output) -> O + 'static,
+        O: ShouldRender,
+    {
+        let abort = Rc::new(Abort::default());
+        let task = Task {
+            abort: Some(abort.clone()),
+        };
+        let signal = self.clone();
+
+        spawn_local(async move {
+            let mut future = pin!(future);
+
+            let output = poll_fn(|cx| {
+                if abort.aborted.get() {
+                    return Poll::Ready(None);
+                }
+
+                let waker = match abort.waker.take() {
+                    Some(waker) if waker.will_wake(cx.waker()) => waker,
+                    _ => cx.waker().clone(),
+                };
+                abort.waker.set(Some(waker));
+
+                future.as_mut().poll(cx).map(Some)
+            })
+            .await;
+
+            if let Some(output) = output {
+                signal.update(move |state| mutator(state, output));
+            }
+        });
+
+        task
+    }
+
+    /// Call `callback` with the state and the frame timestamp on every animation frame,
+    /// rendering according to its return value the same way [`update`](Signal::update) does.
+    ///
+    /// The loop keeps going until the returned [`AnimationFrames`] guard is dropped, returning
+    /// it from [`once`](crate::stateful::Stateful::once) ties it to the lifetime of the component:
+    ///
+    /// ```
+    /// # use kobold::prelude::*;
+    /// #[component]
+    /// fn spinner() -> impl View {
+    ///     stateful(0.0, |angle: &Hook<f64>| {
+    ///         let style = format!("transform: rotate({}deg)", **angle);
+    ///
+    ///         view! { <div.spinner {style}></div> }
+    ///     })
+    ///     .once(|signal| {
+    ///         // One full turn per second, in sync with the display
+    ///         signal.animation_frames(|angle, timestamp| *angle = timestamp * 0.36 % 360.0)
+    ///     })
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn animation_frames<F, O>(&self, callback: F) -> AnimationFrames
+    where
+        S: 'static,
+        F: FnMut(&mut S, f64) -> O + 'static,
+        O: ShouldRender,
+    {
+        let signal = self.clone();
+        let mut callback = callback;
+
+        let frames = Rc::new(Frames {
+            callback: RefCell::new(Box::new(move |timestamp| {
+                signal.update(|state| callback(state, timestamp))
+            })),
+        });
+
+        frames.request();
+
+        AnimationFrames { _frames: frames }
+    }
+}
+
+/// Render the state on the next animation frame, unless a render is already scheduled.
+fn request_frame<S: 'static>(inner: &Rc<Inner<S>>) {
+    if inner.frame.get() {
+        return;
+    }
+
+    let weak = Rc::downgrade(inner);
+    let render = Closure::once_into_js(move || {
+        if let Some(inner) = weak.upgrade() {
+            inner.frame.set(false);
+            inner.update();
+        }
+    });
+
+    match web_sys::window().map(|window| window.request_animation_frame(render.unchecked_ref())) {
+        Some(Ok(_)) => inner.frame.set(true),
+        // No frames to wait for, render right away
+        _ => inner.update(),
+    }
+}
+
+/// Handle to a future spawned with [`Signal::async_update`].
+///
+/// The future is aborted when this handle is dropped, unless it has been [`detach`ed](Task::detach).
+#[must_use = "the future is aborted as soon as the `Task` is dropped, use `Task::detach` to let it run"]
+pub struct Task {
+    abort: Option<Rc<Abort>>,
+}
+
+#[derive(Default)]
+struct Abort {
+    aborted: Cell<bool>,
+    waker: Cell<Option<Waker>>,
+}
+
+impl Task {
+    /// Abort the future, same as dropping this handle.
+    pub fn abort(self) {}
+
+    /// Let the future run to completion regardless of this handle.
+    pub fn detach(mut self) {
+        self.abort = None;
+    }
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {
+        if let Some(abort) = self.abort.take() {
+            abort.aborted.set(true);
+
+            // Wake the task so that the future is dropped right away
+            if let Some(waker) = abort.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Guard of an animation frame loop started with [`Signal::animation_frames`].
+///
+/// No more frames are requested once this guard is dropped.
+#[must_use = "the loop stops as soon as the `AnimationFrames` guard is dropped"]
+pub struct AnimationFrames {
+    // the only strong reference to the loop state, see `Frames::request`
+    _frames: Rc<Frames>,
+}
+
+struct Frames {
+    callback: RefCell<Box<dyn FnMut(f64)>>,
+}
+
+impl Frames {
+    fn request(self: &Rc<Self>) {
+        // Only the guard holds a strong reference, a frame that fires after it was
+        // dropped finds nothing to upgrade and ends the loop.
+        let weak = Rc::downgrade(self);
+        let frame = Closure::once_into_js(move |timestamp: f64| {
+            if let Some(frames) = weak.upgrade() {
+                (frames.callback.borrow_mut())(timestamp);
+                frames.request();
+            }
+        });
+
+        if let Some(window) = web_sys::window() {
+            let _ = window.request_animation_frame(frame.unchecked_ref());
+        }
+    }
 }
 
 impl<S> Clone for Signal<S> {
@@ -86,6 +367,93 @@ impl<S> Clone for Signal<S> {
     }
 }
 
+/// A [`Signal`] to a part of the state, created by [`Signal::map`].
+///
+/// Every update goes through the projection closure, so plain `Signal`s don't
+/// pay for it. Mapped signals can be mapped further.
+pub struct MappedSignal<T> {
+    project: Projection<T>,
+}
+
+impl<T> MappedSignal<T> {
+    fn with<F, O>(&self, mutator: F, frame: bool)
+    where
+        F: FnOnce(&mut T) -> O,
+        O: ShouldRender,
+    {
+        let mut mutator = Some(mutator);
+
+        (self.project)(
+            &mut |state| match mutator.take() {
+                Some(mutator) => mutator(state).should_render(),
+                None => false,
+            },
+            frame,
+        );
+    }
+
+    /// Update the part of the state behind this signal, see [`Signal::update`].
+    pub fn update<F, O>(&self, mutator: F)
+    where
+        F: FnOnce(&mut T) -> O,
+        O: ShouldRender,
+    {
+        self.with(mutator, false);
+    }
+
+    /// Same as [`update`](MappedSignal::update), but the render is scheduled for the
+    /// next animation frame, see [`Signal::update_raf`].
+    pub fn update_raf<F, O>(&self, mutator: F)
+    where
+        F: FnOnce(&mut T) -> O,
+        O: ShouldRender,
+    {
+        self.with(mutator, true);
+    }
+
+    /// Same as [`update`](MappedSignal::update), but it never renders updates.
+    pub fn update_silent<F>(&self, mutator: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        self.with(
+            move |state| {
+                mutator(state);
+                false
+            },
+            false,
+        );
+    }
+
+    /// Replace this part of the state with a new value and trigger an update.
+    pub fn set(&self, val: T) {
+        self.update(move |s| *s = val);
+    }
+
+    /// Map this signal further, see [`Signal::map`].
+    pub fn map<U, F>(&self, project: F) -> MappedSignal<U>
+    where
+        T: 'static,
+        F: Fn(&mut T) -> &mut U + 'static,
+    {
+        let parent = self.project.clone();
+
+        MappedSignal {
+            project: Rc::new(move |mutator: &mut Mutator<U>, frame: bool| {
+                parent(&mut |state| mutator(project(state)), frame)
+            }),
+        }
+    }
+}
+
+impl<T> Clone for MappedSignal<T> {
+    fn clone(&self) -> Self {
+        MappedSignal {
+            project: self.project.clone(),
+        }
+    }
+}
+
 impl<S> Hook<S> {
     pub(super) fn new(inner: &Inner<S>) -> &Self {
         unsafe { &*(inner as *const _ as *const Hook<S>) }
@@ -105,6 +473,87 @@ impl<S> Hook<S> {
         Bound { inner, callback }
     }
 
+    /// Same as [`bind`](Hook::bind), but the closure also receives the value of the
+    /// current target of the event, which can be an `<input>`, a `<textarea>`, a `<select>`,
+    /// or anything else implementing [`TargetValue`].
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    ///
+    /// #[component]
+    /// fn name_input() -> impl View {
+    ///     stateful(String::new, |name| {
+    ///         view! {
+    ///             <p>"Hello, "{ name.as_str() }</p>
+    ///             <input oninput={name.bind_input(|name, value, _| *name = value)}>
+    ///         }
+    ///     })
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn bind_input<E, F, O>(
+        &self,
+        callback: F,
+    ) -> Bound<'_, S, impl Fn(&mut S, E) -> O + 'static>
+    where
+        S: 'static,
+        E: EventCast,
+        E::Target: TargetValue + JsCast,
+        F: Fn(&mut S, String, E) -> O + 'static,
+        O: ShouldRender,
+    {
+        self.bind(move |state, e: E| {
+            let value = event::current_target(&e).value();
+
+            callback(state, value, e)
+        })
+    }
+
+    /// Same as [`bind`](Hook::bind), but calls [`prevent_default`](web_sys::Event::prevent_default)
+    /// on the event before the closure, see [`on_submit`](crate::event::on_submit).
+    ///
+    /// ```
+    /// use kobold::prelude::*;
+    /// use wasm_bindgen::JsCast;
+    /// use web_sys::{HtmlFormElement, HtmlInputElement};
+    ///
+    /// #[component]
+    /// fn search() -> impl View {
+    ///     stateful(String::new, |query| {
+    ///         let onsubmit = query.bind_submit(|query, e: Event<HtmlFormElement>| {
+    ///             if let Some(input) = e.current_target().get_with_name("q") {
+    ///                 *query = input.unchecked_into::<HtmlInputElement>().value();
+    ///             }
+    ///         });
+    ///
+    ///         view! {
+    ///             <form {onsubmit}>
+    ///                 <input name="q">
+    ///                 <button>"Search"</button>
+    ///             </form>
+    ///             <p>"Results for: "{ query.as_str() }</p>
+    ///         }
+    ///     })
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn bind_submit<E, F, O>(
+        &self,
+        callback: F,
+    ) -> Bound<'_, S, impl Fn(&mut S, E) -> O + 'static>
+    where
+        S: 'static,
+        E: EventCast,
+        F: Fn(&mut S, E) -> O + 'static,
+        O: ShouldRender,
+    {
+        self.bind(move |state, e: E| {
+            e.as_event().prevent_default();
+
+            callback(state, e)
+        })
+    }
+
     pub fn bind_async<E, F, T>(&self, callback: F) -> impl Listener<E>
     where
         S: 'static,
@@ -126,9 +575,7 @@ impl<S> Hook<S> {
             // need it to construct a `Weak` reference to `Inner`.
             let rc = ManuallyDrop::new(unsafe { Rc::from_raw(inner) });
 
-            let signal = Signal {
-                weak: Rc::downgrade(&*rc),
-            };
+            let signal = Signal::new(Rc::downgrade(&*rc));
 
             spawn_local(callback(signal, e));
         }
@@ -142,6 +589,33 @@ impl<S> Hook<S> {
     {
         **self
     }
+
+    /// Compute a value from the state. This is equivalent to reading the state through
+    /// `Deref`, but it's the forward-compatible way of doing so: should Kobold ever track
+    /// which parts of the state a view reads, reads made through `with` will be tracked.
+    ///
+    /// ```
+    /// # use kobold::prelude::*;
+    /// struct Cart {
+    ///     prices: Vec<u32>,
+    /// }
+    ///
+    /// #[component]
+    /// fn cart() -> impl View {
+    ///     stateful(|| Cart { prices: vec![5, 10] }, |cart| {
+    ///         let total = cart.with(|cart| cart.prices.iter().sum::<u32>());
+    ///
+    ///         view! { <p>"Total: "{ total }</p> }
+    ///     })
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn with<'a, F, T>(&'a self, f: F) -> T
+    where
+        F: FnOnce(&'a S) -> T,
+    {
+        f(self)
+    }
 }
 
 pub struct Bound<'b, S, F> {
@@ -160,7 +634,7 @@ impl<S, F> Bound<'_, S, F> {
         let Bound { inner, callback } = self;
 
         let inner = inner as *const Inner<S>;
-        let bound = move |e| {
+        let bound = move |e: E| {
             // ⚠️ Safety:
             // ==========
             //
@@ -170,7 +644,17 @@ impl<S, F> Bound<'_, S, F> {
             let inner = unsafe { &*inner };
             let state = unsafe { inner.state.mut_unchecked() };
 
-            if callback(state, e).should_render() {
+            // Const branch, only handlers that can stop propagation keep the event around.
+            let event = O::CAN_STOP_PROPAGATION.then(|| e.as_event().clone());
+            let then = callback(state, e);
+
+            if let Some(event) = event {
+                if then.stop_propagation() {
+                    event.stop_propagation();
+                }
+            }
+
+            if then.should_render() {
                 inner.update();
             }
         };
@@ -249,6 +733,11 @@ where
     fn update(self, p: &mut Self::Product) {
         (**self).update(p)
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (**self).render_html(out)
+    }
 }
 
 #[cfg(test)]
@@ -258,6 +747,7 @@ mod test {
 
     use crate::stateful::cell::WithCell;
     use crate::stateful::product::ProductHandler;
+    use crate::stateful::Then;
     use crate::value::TextProduct;
 
     use super::*;
@@ -266,6 +756,7 @@ mod test {
     fn bound_callback_is_copy() {
         let inner = Inner {
             state: WithCell::new(0_i32),
+            frame: Cell::new(false),
             prod: UnsafeCell::new(ProductHandler::mock(
                 |_, _| {},
                 TextProduct {
@@ -285,4 +776,195 @@ mod test {
         // Make sure we can copy the mock twice
         let _ = [mock, mock];
     }
+
+    type MockInner<S> =
+        Inner<S, ProductHandler<S, TextProduct<i32>, fn(*const Hook<S>, *mut TextProduct<i32>)>>;
+
+    fn mock_inner<S>(state: S) -> MockInner<S> {
+        Inner {
+            state: WithCell::new(state),
+            frame: Cell::new(false),
+            prod: UnsafeCell::new(ProductHandler::mock(
+                |_, _| {},
+                TextProduct {
+                    memo: 0,
+                    node: wasm_bindgen::JsValue::UNDEFINED.unchecked_into(),
+                },
+            )),
+        }
+    }
+
+    /// Call the callback of a `Bound` as the event listener would,
+    /// returning whether the view should render.
+    fn fire<S, F, O>(bound: Bound<S, F>, state: &mut S) -> bool
+    where
+        F: Fn(&mut S, web_sys::Event) -> O,
+        O: ShouldRender,
+    {
+        let event = wasm_bindgen::JsValue::UNDEFINED.unchecked_into();
+
+        (bound.callback)(state, event).should_render()
+    }
+
+    #[test]
+    fn do_mutation_renders() {
+        let inner = mock_inner(0_i32);
+        let count = Hook::new(&inner);
+        let mut state = 0;
+
+        assert!(fire(crate::event!(*count += 1), &mut state));
+        assert_eq!(state, 1);
+    }
+
+    #[test]
+    fn do_method_call_renders() {
+        let inner = mock_inner(String::new());
+        let name = Hook::new(&inner);
+        let mut state = String::from("Kobold");
+
+        assert!(fire(crate::event!(name.push('!')), &mut state));
+        assert_eq!(state, "Kobold!");
+    }
+
+    #[test]
+    fn do_then_stop_mutates_without_render() {
+        let inner = mock_inner(0_i32);
+        let count = Hook::new(&inner);
+        let mut state = 0;
+
+        let silent = crate::event!(|count| {
+            *count += 1;
+            Then::Stop
+        });
+
+        assert!(!fire(silent, &mut state));
+        assert_eq!(state, 1);
+
+        let loud = crate::event!(|count| {
+            *count += 1;
+            Then::Render
+        });
+
+        assert!(fire(loud, &mut state));
+        assert_eq!(state, 2);
+    }
+
+    #[test]
+    fn then_stop_propagation_renders() {
+        use crate::stateful::ShouldRender;
+
+        assert!(Then::StopPropagation.stop_propagation());
+        assert!(Then::StopPropagationSilent.stop_propagation());
+        assert!(!Then::Render.stop_propagation());
+        assert!(!Then::Stop.stop_propagation());
+        assert!(!().stop_propagation());
+
+        let inner = mock_inner(0_i32);
+        let count = Hook::new(&inner);
+        let mut state = 0;
+
+        let stop = crate::event!(|count| {
+            *count += 1;
+            Then::StopPropagation
+        });
+
+        assert!(fire(stop, &mut state));
+        assert_eq!(state, 1);
+    }
+
+    #[test]
+    fn then_stop_propagation_silent_skips_render() {
+        let inner = mock_inner(0_i32);
+        let count = Hook::new(&inner);
+        let mut state = 0;
+
+        let stop = crate::event!(|count| {
+            *count += 1;
+            Then::StopPropagationSilent
+        });
+
+        assert!(!fire(stop, &mut state));
+        assert_eq!(state, 1);
+    }
+
+    #[test]
+    fn bool_renders_if_true() {
+        let inner = mock_inner(0_i32);
+        let count = Hook::new(&inner);
+        let mut state = 0;
+
+        let capped = crate::event!(|count| {
+            let before = *count;
+
+            *count = (*count + 1).min(1);
+            *count != before
+        });
+
+        assert!(fire(capped, &mut state));
+        assert!(!fire(capped, &mut state));
+        assert_eq!(state, 1);
+    }
+
+    #[test]
+    fn conversions_into_then() {
+        use crate::stateful::ShouldRender;
+
+        for (then, render) in [
+            (Then::from(()), true),
+            (Then::from(true), true),
+            (Then::from(false), false),
+        ] {
+            assert_eq!(then.should_render(), render);
+        }
+
+        assert!(().should_render());
+        assert!(true.should_render());
+        assert!(!false.should_render());
+        assert!(!true.stop_propagation());
+    }
+
+    #[test]
+    fn mapped_signal_renders_parent() {
+        struct User {
+            name: String,
+            age: u32,
+            renders: Cell<u32>,
+        }
+
+        let inner: Rc<MockInner<User>> = Rc::new(Inner {
+            state: WithCell::new(User {
+                name: String::new(),
+                age: 0,
+                renders: Cell::new(0),
+            }),
+            frame: Cell::new(false),
+            prod: UnsafeCell::new(ProductHandler::mock(
+                |hook, _| {
+                    let renders = unsafe { &(&*hook).renders };
+
+                    renders.set(renders.get() + 1);
+                },
+                TextProduct {
+                    memo: 0,
+                    node: wasm_bindgen::JsValue::UNDEFINED.unchecked_into(),
+                },
+            )),
+        });
+
+        let user = Signal::new(Rc::downgrade(&inner) as Weak<Inner<User>>);
+        let name = user.map(|user| &mut user.name);
+        let age = user.map(|user| &mut user.age);
+
+        name.set("Alice".into());
+        age.update(|age| {
+            *age += 1;
+            Then::Stop
+        });
+
+        let user = unsafe { inner.state.ref_unchecked() };
+
+        assert_eq!(user.name, "Alice");
+        assert_eq!(user.age, 1);
+        assert_eq!(user.renders.get(), 1);
+    }
 }
@@ -6,6 +6,14 @@ use crate::diff::Diff;
 use crate::stateful::Then;
 
 /// Trait used to create stateful components, see [`stateful`](crate::stateful::stateful) for details.
+///
+/// There are three ways to get some state:
+///
+/// * Primitive values and string references are diffed, the view renders when they change.
+/// * Closures and functions with no parameters are called once to initialize the state.
+/// * Any struct or enum can use `#[derive(IntoState)]`, the value itself becomes the state.
+///   Just like with closures it's only used to initialize the state, so there is no need
+///   to wrap a constructor call in a closure.
 pub trait IntoState: Sized {
     type State: 'static;
 
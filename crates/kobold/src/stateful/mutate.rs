@@ -0,0 +1,77 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Mutation helpers for state inside event handlers, mirroring the API of [`Signal`](crate::stateful::Signal).
+
+use crate::stateful::{ShouldRender, Then};
+
+/// Replace the entire `state`, or any of its fields, with a new `value`, always
+/// rendering the view afterwards.
+///
+/// Handlers created with [`event!`](crate::event) or the [`do`](crate::keywords::do) keyword
+/// get a mutable reference to the state of the [`Hook`](crate::stateful::Hook) shadowing its name.
+/// Outside of handlers, such as in async code or callbacks from other libraries, use
+/// [`Signal::set`](crate::stateful::Signal::set) instead.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// use kobold::stateful::{modify, set};
+///
+/// struct Form {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[component]
+/// fn form() -> impl View {
+///     stateful(|| Form { name: String::new(), age: 0 }, |form| {
+///         view! {
+///             <p>{ ref form.name }" is "{ form.age }</p>
+///             <button onclick={do |form| set(&mut form.name, "Alice".into())}>"Alice"</button>
+///             <button onclick={do |form| modify(&mut form.age, |age| {
+///                 let old = *age;
+///
+///                 *age = age.saturating_sub(1);
+///
+///                 Then::render_if(*age != old)
+///             })}>"Younger"</button>
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
+pub fn set<T>(state: &mut T, value: T) -> Then {
+    *state = value;
+
+    Then::Render
+}
+
+/// Mutate `state`, or any of its fields, with a closure, its return value decides whether
+/// the view renders just like with [`Signal::update`](crate::stateful::Signal::update).
+/// See [`set`] for an example.
+///
+/// ```
+/// use kobold::stateful::{modify, Then};
+///
+/// let mut count = 1;
+///
+/// assert!(matches!(modify(&mut count, |count| *count += 1), Then::Render));
+/// assert!(matches!(modify(&mut count, |_| Then::Stop), Then::Stop));
+/// assert_eq!(count, 2);
+/// ```
+pub fn modify<T, F, O>(state: &mut T, mutator: F) -> Then
+where
+    T: ?Sized,
+    F: FnOnce(&mut T) -> O,
+    O: ShouldRender,
+{
+    let out = mutator(state);
+    let stop = out.stop_propagation();
+
+    match (out.should_render(), stop) {
+        (true, true) => Then::StopPropagation,
+        (false, true) => Then::StopPropagationSilent,
+        (render, false) => Then::render_if(render),
+    }
+}
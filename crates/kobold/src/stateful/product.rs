@@ -20,6 +20,24 @@ pub trait Product<S> {
     fn replace_with(&self, new: &JsValue);
 }
 
+/// Placeholder for the product of a stateful view that is only ever
+/// rendered to HTML, see [`ssr`](crate::ssr).
+#[cfg(feature = "ssr")]
+pub struct Unmounted;
+
+#[cfg(feature = "ssr")]
+impl<S> Product<S> for Unmounted {
+    fn update(&mut self, _: &Hook<S>) {}
+
+    fn js(&self) -> &JsValue {
+        unreachable!("Views rendered to HTML are never mounted")
+    }
+
+    fn unmount(&self) {}
+
+    fn replace_with(&self, _: &JsValue) {}
+}
+
 pub struct ProductHandler<S, P, F> {
     updater: F,
     product: P,
@@ -0,0 +1,96 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::event::EventCast;
+use crate::stateful::{Bound, Hook, Then};
+
+/// Bind a group of radio inputs to the state of a `hook`, yielding a [`Radio`]
+/// for each of the `options`.
+///
+/// The `checked` attribute is always synced with the DOM, so the selected input
+/// follows the state even if it changes from elsewhere, and selecting an input
+/// updates the state through its `onchange` listener.
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::stateful::radio_group;
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Size {
+///     Small,
+///     Large,
+/// }
+///
+/// impl Size {
+///     fn label(self) -> &'static str {
+///         match self {
+///             Size::Small => "Small",
+///             Size::Large => "Large",
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn size_picker() -> impl View {
+///     stateful(|| Size::Small, |size| {
+///         view! {
+///             <fieldset>
+///             {
+///                 for radio_group(size, [Size::Small, Size::Large]).map(|radio| view! {
+///                     <label>
+///                         <input type="radio" name="size" checked={radio.checked()} onchange={radio.onchange()}>
+///                         { static radio.value().label() }
+///                     </label>
+///                 })
+///             }
+///             </fieldset>
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
+pub fn radio_group<'a, S, I>(hook: &'a Hook<S>, options: I) -> impl Iterator<Item = Radio<'a, S>>
+where
+    S: Copy + PartialEq + 'static,
+    I: IntoIterator<Item = S>,
+{
+    options.into_iter().map(move |value| Radio { hook, value })
+}
+
+/// A single option of a [`radio_group`].
+pub struct Radio<'a, S> {
+    hook: &'a Hook<S>,
+    value: S,
+}
+
+impl<'a, S> Radio<'a, S>
+where
+    S: Copy + PartialEq + 'static,
+{
+    /// The value of the state this option stands for.
+    pub fn value(&self) -> S {
+        self.value
+    }
+
+    /// Whether this option is currently selected, to be used with the `checked` attribute.
+    pub fn checked(&self) -> bool {
+        **self.hook == self.value
+    }
+
+    /// Event listener selecting this option, renders only if the state has changed.
+    pub fn onchange<E>(&self) -> Bound<'a, S, impl Fn(&mut S, E) -> Then + 'static>
+    where
+        E: EventCast,
+    {
+        let value = self.value;
+
+        self.hook.bind(move |state: &mut S, _: E| {
+            let changed = *state != value;
+
+            *state = value;
+
+            Then::render_if(changed)
+        })
+    }
+}
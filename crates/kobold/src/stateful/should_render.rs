@@ -7,8 +7,58 @@
 ///
 /// * [`Hook::bind`](crate::stateful::Hook::bind)
 /// * [`IntoState::update`](crate::stateful::IntoState::update)
+///
+/// Closures updating state can return any of these types:
+///
+/// | Return type                     | Renders | Stops propagation |
+/// |---------------------------------|---------|-------------------|
+/// | `()`                            | yes     | no                |
+/// | `true`                          | yes     | no                |
+/// | `false`                         | no      | no                |
+/// | [`Then::Render`]                | yes     | no                |
+/// | [`Then::Stop`]                  | no      | no                |
+/// | [`Then::StopPropagation`]       | yes     | yes               |
+/// | [`Then::StopPropagationSilent`] | no      | yes               |
+///
+/// A closure that doesn't return anything is assumed to have changed the state, the same
+/// as returning [`Then::Render`], and each type converts into the matching [`Then`] with
+/// [`Into`]. Return a `bool` or a [`Then`] to skip renders when nothing has changed:
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[component]
+/// fn counter() -> impl View {
+///     stateful(0_u32, |count| {
+///         view! {
+///             <button onclick={do |count| {
+///                 // Counts up to 10, only renders if the count actually changed
+///                 let before = *count;
+///
+///                 *count = (*count + 1).min(10);
+///                 *count != before
+///             }}>"Clicks: "{ count.get() }</button>
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Event handlers bound to a hook can additionally stop the event from propagating
+/// further up the DOM, see [`Then::StopPropagation`] and [`Then::StopPropagationSilent`].
+/// Plain closures used as event listeners never render and leave propagation alone.
 pub trait ShouldRender: 'static {
+    /// Set to `true` if [`stop_propagation`](ShouldRender::stop_propagation) can
+    /// ever return `true`, so that listeners returning `()` don't have to hold on
+    /// to the event.
+    const CAN_STOP_PROPAGATION: bool = false;
+
     fn should_render(self) -> bool;
+
+    /// Whether the event that triggered this update should stop propagating,
+    /// ignored outside of event handlers.
+    fn stop_propagation(&self) -> bool {
+        false
+    }
 }
 
 /// Closures without return type always update their view.
@@ -18,23 +68,105 @@ impl ShouldRender for () {
     }
 }
 
+/// `true` renders the view, `false` is a silent update.
+impl ShouldRender for bool {
+    fn should_render(self) -> bool {
+        self
+    }
+}
+
 /// An enum that implements the [`ShouldRender`](ShouldRender) trait.
 /// See:
 ///
 /// * [`Hook::bind`](crate::stateful::Hook::bind)
 /// * [`IntoState::update`](crate::stateful::IntoState::update)
+///
+/// Neither [`Then::Stop`] nor [`Then::Render`] affect the event itself, which keeps
+/// bubbling up to any handlers on the parent elements.
+///
+/// ```
+/// # use kobold::prelude::*;
+/// #[component]
+/// fn counter() -> impl View {
+///     stateful(0_u32, |count| {
+///         view! {
+///             // Counts clicks anywhere inside the div
+///             <div onclick={do *count += 1}>
+///                 <p>"Clicks: "{ count.get() }</p>
+///                 // Resets the count without the click reaching the div
+///                 <button onclick={do |count| {
+///                     *count = 0;
+///
+///                     Then::StopPropagation
+///                 }}>"Reset"</button>
+///             </div>
+///         }
+///     })
+/// }
+/// # fn main() {}
+/// ```
 pub enum Then {
     /// This is a silent update
     Stop,
     /// Render the view after this update
     Render,
+    /// Render the view after this update, and stop the event that triggered it
+    /// from propagating to handlers on parent elements
+    StopPropagation,
+    /// Stop the event that triggered this update from propagating to handlers
+    /// on parent elements without rendering the view
+    StopPropagationSilent,
+}
+
+impl Then {
+    /// Render the view only if `cond` is `true`, equivalent to [`Then::from(cond)`](Then::from).
+    ///
+    /// ```
+    /// # use kobold::prelude::*;
+    /// fn example(name: Signal<String>) {
+    ///     name.update(|name| {
+    ///         let changed = name != "Alice";
+    ///
+    ///         "Alice".clone_into(name);
+    ///
+    ///         Then::render_if(changed)
+    ///     });
+    /// }
+    /// ```
+    pub const fn render_if(cond: bool) -> Self {
+        if cond {
+            Then::Render
+        } else {
+            Then::Stop
+        }
+    }
+}
+
+/// `true` maps to [`Then::Render`] and `false` to [`Then::Stop`].
+impl From<bool> for Then {
+    fn from(cond: bool) -> Self {
+        Then::render_if(cond)
+    }
+}
+
+/// `()` maps to [`Then::Render`], same as closures without return type.
+impl From<()> for Then {
+    fn from(_: ()) -> Self {
+        Then::Render
+    }
 }
 
 impl ShouldRender for Then {
+    const CAN_STOP_PROPAGATION: bool = true;
+
     fn should_render(self) -> bool {
         match self {
-            Then::Stop => false,
-            Then::Render => true,
+            Then::Stop | Then::StopPropagationSilent => false,
+            Then::Render | Then::StopPropagation => true,
         }
     }
+
+    fn stop_propagation(&self) -> bool {
+        matches!(self, Then::StopPropagation | Then::StopPropagationSilent)
+    }
 }
@@ -0,0 +1,137 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Utilities for testing views, enabled with the `test` feature.
+//!
+//! Views can only be built in the DOM, so tests need to run in a browser,
+//! for example using [`wasm-bindgen-test`](https://docs.rs/wasm-bindgen-test):
+//!
+//! ```no_run
+//! use kobold::prelude::*;
+//!
+//! #[component]
+//! fn counter(count: u32) -> impl View {
+//!     view! { <p.count>"Count: "{ count }</p> }
+//! }
+//!
+//! // Annotate with `#[wasm_bindgen_test]`
+//! fn renders_count() {
+//!     let mut counter = kobold::test::render(view! { <!counter count={1}> });
+//!
+//!     assert_eq!(counter.html(), r#"<p class="count">Count: 1</p>"#);
+//!
+//!     counter.update(view! { <!counter count={2}> });
+//!
+//!     assert_eq!(counter.text_of(".count").as_deref(), Some("Count: 2"));
+//! }
+//! # fn main() {}
+//! ```
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Event, HtmlElement};
+
+use crate::dom::Mountable;
+use crate::{Mount, View};
+
+/// Build the `view` into a container element detached from the document,
+/// returning a [`TestHandle`] that can be used to inspect and update it.
+pub fn render<V: View>(view: V) -> TestHandle<V::Product> {
+    let container = web_sys::window()
+        .and_then(|window| window.document())
+        .expect("`kobold::test` requires a browser environment")
+        .create_element("div")
+        .expect("failed to create a container element");
+
+    let mount = crate::mount(&container, view);
+
+    TestHandle { container, mount }
+}
+
+/// Handle to a view rendered with [`render`]. The view is unmounted when
+/// the handle is dropped.
+pub struct TestHandle<P: Mountable> {
+    container: Element,
+    mount: Mount<P>,
+}
+
+impl<P: Mountable> TestHandle<P> {
+    /// Update the rendered view, applying any changes to the DOM.
+    pub fn update<V>(&mut self, view: V)
+    where
+        V: View<Product = P>,
+    {
+        self.mount.update(view);
+    }
+
+    /// The product of the rendered view.
+    pub fn product(&self) -> &P {
+        &self.mount.product
+    }
+
+    /// The element containing the rendered view.
+    pub fn container(&self) -> &Element {
+        &self.container
+    }
+
+    /// HTML of the rendered view.
+    pub fn html(&self) -> String {
+        self.container.inner_html()
+    }
+
+    /// Text content of the rendered view.
+    pub fn text(&self) -> String {
+        self.container.text_content().unwrap_or_default()
+    }
+
+    /// First element matching a CSS `selector`.
+    ///
+    /// Panics if the `selector` is invalid.
+    pub fn query(&self, selector: &str) -> Option<Element> {
+        self.container
+            .query_selector(selector)
+            .unwrap_or_else(|_| panic!("invalid selector: {selector}"))
+    }
+
+    /// All elements matching a CSS `selector`.
+    ///
+    /// Panics if the `selector` is invalid.
+    pub fn query_all(&self, selector: &str) -> Vec<Element> {
+        let list = self
+            .container
+            .query_selector_all(selector)
+            .unwrap_or_else(|_| panic!("invalid selector: {selector}"));
+
+        (0..list.length())
+            .filter_map(|idx| list.item(idx)?.dyn_into().ok())
+            .collect()
+    }
+
+    /// Text content of the first element matching a CSS `selector`.
+    pub fn text_of(&self, selector: &str) -> Option<String> {
+        self.query(selector)?.text_content()
+    }
+
+    /// Dispatch an `event` on the first element matching a CSS `selector`,
+    /// returns `false` if the event was cancelled by a handler.
+    ///
+    /// Panics if no element matches the `selector`.
+    pub fn dispatch(&self, selector: &str, event: &Event) -> bool {
+        self.expect(selector)
+            .dispatch_event(event)
+            .expect("failed to dispatch event")
+    }
+
+    /// Click the first element matching a CSS `selector`.
+    ///
+    /// Panics if no element matches the `selector`.
+    pub fn click(&self, selector: &str) {
+        self.expect(selector)
+            .unchecked_into::<HtmlElement>()
+            .click();
+    }
+
+    fn expect(&self, selector: &str) -> Element {
+        self.query(selector)
+            .unwrap_or_else(|| panic!("no element matching selector: {selector}"))
+    }
+}
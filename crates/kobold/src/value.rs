@@ -2,6 +2,34 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+//! Text values and views formatting numbers
+//!
+//! Numbers can be rendered directly in the [`view!`](crate::view) macro, the
+//! [`fixed`] and [`grouped`] functions can be used to format them first.
+//!
+//! Arbitrary formatted text can be written into a reusable buffer with [`scratch`].
+//!
+//! Paths and OS strings are rendered as text without converting them first, any invalid
+//! UTF-8 is replaced with `�`:
+//!
+//! ```
+//! use std::path::Path;
+//! use kobold::prelude::*;
+//!
+//! #[component]
+//! fn entry(path: &Path) -> impl View + '_ {
+//!     view! {
+//!         <li.entry>{ path.file_name().unwrap_or_default() }" in "{ path }</li>
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+
+use std::cell::RefCell;
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
 use web_sys::Node;
 
 use crate::diff::{Diff, Ref, VString};
@@ -12,6 +40,9 @@ use crate::View;
 /// Value that can be set as a property on DOM node
 pub trait Value<P>: IntoText {
     fn set_prop(self, prop: P, node: &Node);
+
+    #[cfg(feature = "ssr")]
+    fn render_prop(self, prop: P, out: &mut String);
 }
 
 /// Value that can be turned into a DOM `Text` node
@@ -49,6 +80,11 @@ macro_rules! impl_value {
                 fn set_prop(self, prop: P, node: &Node) {
                     prop.set(node, self as _);
                 }
+
+                #[cfg(feature = "ssr")]
+                fn render_prop(self, prop: P, out: &mut String) {
+                    prop.render(self as _, out);
+                }
             }
         )*
     };
@@ -87,6 +123,11 @@ impl View for String {
             p.memo.set_prop(TextContent, &p.node);
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        self.as_str().render_prop(TextContent, out);
+    }
 }
 
 /// A helper trait describing integers that might not fit in the JavaScript
@@ -120,6 +161,14 @@ macro_rules! large_int {
                         Err(_) => self.stringify(|s| prop.set(el, s)),
                     }
                 }
+
+                #[cfg(feature = "ssr")]
+                fn render_prop(self, prop: P, out: &mut String) {
+                    match <$d>::try_from(self) {
+                        Ok(int) => prop.render(int as f64, out),
+                        Err(_) => self.stringify(|s| prop.render(s, out)),
+                    }
+                }
             }
 
             impl IntoText for $t {
@@ -154,6 +203,11 @@ macro_rules! impl_text_view {
                         self.set_prop(TextContent, &p.node);
                     }
                 }
+
+                #[cfg(feature = "ssr")]
+                fn render_html(self, out: &mut String) {
+                    self.render_prop(TextContent, out);
+                }
             }
         )*
     };
@@ -172,6 +226,11 @@ impl<'a> View for &&'a str {
     fn update(self, p: &mut Self::Product) {
         (*self).update(p)
     }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        (*self).render_html(out)
+    }
 }
 
 macro_rules! impl_ref_view {
@@ -187,9 +246,351 @@ macro_rules! impl_ref_view {
                 fn update(self, p: &mut Self::Product) {
                     (*self).update(p)
                 }
+
+                #[cfg(feature = "ssr")]
+                fn render_html(self, out: &mut String) {
+                    (*self).render_html(out)
+                }
             }
         )*
     };
 }
 
 impl_ref_view!(bool, u8, u16, u32, u64, u128, usize, isize, i8, i16, i32, i64, i128, f32, f64);
+
+macro_rules! impl_option_view {
+    ($($ty:ty),*) => {
+        $(
+            /// Renders the text when `Some`, or an empty text node when `None`. Unlike
+            /// the [`Option`] view this always reuses the same text node.
+            ///
+            /// Optional text passed by value, such as `Option<&str>`, is already covered
+            /// by the generic [`Option`] view, so borrow it to reuse the text node.
+            impl View for &Option<$ty> {
+                type Product = <&'static str as View>::Product;
+
+                fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+                    self.as_deref().unwrap_or_default().build(p)
+                }
+
+                fn update(self, p: &mut Self::Product) {
+                    self.as_deref().unwrap_or_default().update(p)
+                }
+
+                #[cfg(feature = "ssr")]
+                fn render_html(self, out: &mut String) {
+                    self.as_deref().unwrap_or_default().render_html(out)
+                }
+            }
+        )*
+    };
+}
+
+impl_option_view!(&str, String);
+
+macro_rules! impl_lossy_view {
+    ($($ty:ty),*) => {
+        $(
+            /// Renders as text, invalid UTF-8 is replaced with `�` (U+FFFD).
+            impl View for $ty {
+                type Product = <&'static str as View>::Product;
+
+                fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+                    self.to_string_lossy().as_ref().build(p)
+                }
+
+                fn update(self, p: &mut Self::Product) {
+                    self.to_string_lossy().as_ref().update(p)
+                }
+
+                #[cfg(feature = "ssr")]
+                fn render_html(self, out: &mut String) {
+                    self.to_string_lossy().as_ref().render_html(out)
+                }
+            }
+        )*
+    };
+}
+
+impl_lossy_view!(&Path, &PathBuf, &OsStr);
+
+thread_local! {
+    static FMT_BUF: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Format `value` into a reusable buffer and pass the result to `f`.
+fn with_formatted<T, F, R>(value: &T, f: F) -> R
+where
+    T: WriteText,
+    F: FnOnce(&str) -> R,
+{
+    FMT_BUF.with(|buf| {
+        let mut buf = buf.borrow_mut();
+
+        buf.clear();
+        value.write_text(&mut buf);
+
+        f(&buf)
+    })
+}
+
+trait WriteText {
+    fn write_text(&self, buf: &mut String);
+}
+
+macro_rules! impl_formatted_view {
+    ($($ty:ident $(<$gen:ident: $bound:path>)?),*) => {
+        $(
+            impl$(<$gen: $bound>)? View for $ty$(<$gen>)? {
+                type Product = <&'static str as View>::Product;
+
+                fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+                    with_formatted(&self, |text| text.build(p))
+                }
+
+                fn update(self, p: &mut Self::Product) {
+                    with_formatted(&self, |text| text.update(p))
+                }
+
+                #[cfg(feature = "ssr")]
+                fn render_html(self, out: &mut String) {
+                    with_formatted(&self, |text| text.render_html(out))
+                }
+            }
+        )*
+    };
+}
+
+impl_formatted_view!(Fixed, Grouped<T: itoa::Integer>);
+
+/// Insert `sep` between every 3 digits of the integer part of a formatted number, in place.
+fn group_digits(buf: &mut String, sep: char) {
+    let start = usize::from(buf.starts_with('-'));
+    let digits = buf[start..].bytes().take_while(u8::is_ascii_digit).count();
+
+    let mut at = start + digits;
+
+    while at > start + 3 {
+        at -= 3;
+        buf.insert(at, sep);
+    }
+}
+
+/// Render a number with a fixed number of `decimals`, see [`Fixed`].
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::value::fixed;
+///
+/// #[component]
+/// fn balance(amount: f64) -> impl View {
+///     view! {
+///         // amount of `1234567.891` renders as "$1,234,567.89"
+///         <p>"$"{ fixed(amount, 2).grouped() }</p>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub const fn fixed(value: f64, decimals: usize) -> Fixed {
+    Fixed {
+        value,
+        decimals,
+        sep: None,
+    }
+}
+
+/// Render an integer with its digits grouped in thousands, see [`Grouped`].
+///
+/// ```
+/// use kobold::prelude::*;
+/// use kobold::value::grouped;
+///
+/// #[component]
+/// fn visitors(count: u64) -> impl View {
+///     view! {
+///         // count of `1234567` renders as "1,234,567 visitors"
+///         <p>{ grouped(count) }" visitors"</p>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub const fn grouped<T>(value: T) -> Grouped<T>
+where
+    T: itoa::Integer,
+{
+    Grouped { value, sep: ',' }
+}
+
+/// A [`View`] of a number with a fixed number of decimals, created with [`fixed`].
+///
+/// The number is formatted into a shared buffer on every render, but the DOM is only
+/// updated when the formatted text has changed.
+pub struct Fixed {
+    value: f64,
+    decimals: usize,
+    sep: Option<char>,
+}
+
+impl Fixed {
+    /// Group digits of the integer part in thousands separated by a comma.
+    pub const fn grouped(self) -> Self {
+        self.separator(',')
+    }
+
+    /// Group digits of the integer part in thousands separated by `sep`.
+    pub const fn separator(mut self, sep: char) -> Self {
+        self.sep = Some(sep);
+        self
+    }
+}
+
+impl WriteText for Fixed {
+    fn write_text(&self, buf: &mut String) {
+        let _ = write!(buf, "{:.*}", self.decimals, self.value);
+
+        if let Some(sep) = self.sep {
+            group_digits(buf, sep);
+        }
+    }
+}
+
+/// A [`View`] of an integer with its digits grouped in thousands, created with [`grouped`].
+///
+/// The number is formatted into a shared buffer on every render, but the DOM is only
+/// updated when the formatted text has changed.
+pub struct Grouped<T> {
+    value: T,
+    sep: char,
+}
+
+impl<T> Grouped<T> {
+    /// Separate the groups with `sep` instead of a comma.
+    pub fn separator(mut self, sep: char) -> Self {
+        self.sep = sep;
+        self
+    }
+}
+
+impl<T: itoa::Integer> WriteText for Grouped<T> {
+    fn write_text(&self, buf: &mut String) {
+        buf.push_str(itoa::Buffer::new().format(self.value));
+
+        group_digits(buf, self.sep);
+    }
+}
+
+/// Render text written into a scratch buffer owned by the product, see [`Scratch`].
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// use kobold::prelude::*;
+/// use kobold::value::scratch;
+///
+/// #[component]
+/// fn position(x: i32, y: i32) -> impl View {
+///     view! {
+///         <p>{ scratch(move |buf| write!(buf, "Position: ({x}, {y})")) }</p>
+///     }
+/// }
+/// # fn main() {}
+/// ```
+pub const fn scratch<F>(write: F) -> Scratch<F>
+where
+    F: FnOnce(&mut String) -> std::fmt::Result,
+{
+    Scratch(write)
+}
+
+/// A [`View`] of text written into a buffer owned by its product, created with [`scratch`].
+///
+/// On build the text is written into a new `String` that is kept for diffing. On every
+/// update the buffer is cleared and written into again, and is swapped with the previous
+/// text if it has changed, in which case the DOM is updated as well. Both allocations are
+/// reused, so after the first update no allocations are made unless the text outgrows the
+/// capacity of the buffers.
+pub struct Scratch<F>(F);
+
+/// Product of a [`Scratch`] view.
+pub struct ScratchProduct {
+    text: TextProduct<String>,
+    buf: String,
+}
+
+impl Anchor for ScratchProduct {
+    type Js = web_sys::Text;
+    type Target = TextProduct<String>;
+
+    fn anchor(&self) -> &TextProduct<String> {
+        &self.text
+    }
+}
+
+impl<F> View for Scratch<F>
+where
+    F: FnOnce(&mut String) -> std::fmt::Result,
+{
+    type Product = ScratchProduct;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        let mut memo = String::new();
+        let _ = (self.0)(&mut memo);
+        let node = memo.as_str().into_text();
+
+        p.put(ScratchProduct {
+            text: TextProduct { memo, node },
+            buf: String::new(),
+        })
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        p.buf.clear();
+        let _ = (self.0)(&mut p.buf);
+
+        if p.buf != p.text.memo {
+            std::mem::swap(&mut p.buf, &mut p.text.memo);
+            p.text.memo.as_str().set_prop(TextContent, &p.text.node);
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        let mut buf = String::new();
+        let _ = (self.0)(&mut buf);
+
+        buf.as_str().render_prop(TextContent, out);
+    }
+}
+
+/// Text from [`format_args!`] is written into the same reusable buffers as [`scratch`].
+///
+/// [`Arguments`](std::fmt::Arguments) borrow temporaries that only live until the end of
+/// the statement, so this only works for views that are consumed right away:
+///
+/// ```no_run
+/// # use kobold::prelude::*;
+/// let (major, minor) = (0, 10);
+///
+/// kobold::start(view! {
+///     <p>{ format_args!("Kobold v{major}.{minor}") }</p>
+/// });
+/// ```
+///
+/// Views returned from components outlive those temporaries, there use `scratch` with
+/// [`write!`] instead, which produces the same output without allocating on updates.
+impl View for std::fmt::Arguments<'_> {
+    type Product = ScratchProduct;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        scratch(move |buf| buf.write_fmt(self)).build(p)
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        scratch(move |buf| buf.write_fmt(self)).update(p)
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(self, out: &mut String) {
+        scratch(move |buf| buf.write_fmt(self)).render_html(out)
+    }
+}
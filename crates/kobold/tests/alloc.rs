@@ -0,0 +1,108 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Assert that building and updating views doesn't allocate where it shouldn't.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold
+//! ```
+#![cfg(target_arch = "wasm32")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::mem::MaybeUninit;
+use std::pin::pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use kobold::internal::In;
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static A: Counting = Counting;
+
+/// Count heap allocations made while running `f`
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+
+    f();
+
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn hello() -> impl View {
+    view! {
+        <div>
+            <h1>"Hello"</h1>
+            <p>"Static HTML is built in one go"</p>
+        </div>
+    }
+}
+
+fn list(len: u32) -> impl View {
+    view! {
+        <ul>
+        {
+            for (0..len).map(|n| view! { <li>{ n }</li> })
+        }
+        </ul>
+    }
+}
+
+#[wasm_bindgen_test]
+fn build_static_view() {
+    let count = allocations(|| {
+        let product = pin!(MaybeUninit::uninit());
+        let _ = In::pinned(product, |p| hello().build(p));
+    });
+
+    assert_eq!(count, 0);
+}
+
+#[wasm_bindgen_test]
+fn update_static_view() {
+    let product = pin!(MaybeUninit::uninit());
+    let mut product = In::pinned(product, |p| hello().build(p));
+
+    let count = allocations(|| hello().update(&mut product));
+
+    assert_eq!(count, 0);
+}
+
+#[wasm_bindgen_test]
+fn update_list_without_growing() {
+    let product = pin!(MaybeUninit::uninit());
+    let mut product = In::pinned(product, |p| list(10).build(p));
+
+    let count = allocations(|| {
+        list(10).update(&mut product);
+        list(5).update(&mut product);
+        list(10).update(&mut product);
+    });
+
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,67 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Updating type-erased views.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::any::AnyView;
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn text(text: &'static str) -> impl View {
+    view! { <p>{ text }</p> }
+}
+
+#[component]
+fn number(n: i32) -> impl View {
+    view! { <b>{ n }</b> }
+}
+
+#[wasm_bindgen_test]
+fn same_type_updates_in_place() {
+    let mut any = kobold::test::render(AnyView::new(view! { <!text text="a"> }));
+    let p = any.query("p").unwrap();
+
+    any.update(AnyView::new(view! { <!text text="b"> }));
+
+    assert_eq!(any.html(), "<p>b</p>");
+    assert!(any.query("p").unwrap() == p);
+}
+
+#[wasm_bindgen_test]
+fn different_type_is_rebuilt() {
+    let mut any = kobold::test::render(AnyView::new(view! { <!text text="a"> }));
+
+    any.update(AnyView::new(view! { <!number n={42}> }));
+
+    assert_eq!(any.html(), "<b>42</b>");
+}
+
+#[component]
+fn widgets(n: i32) -> impl View {
+    let widgets = vec![
+        AnyView::new(view! { <!text text="a"> }),
+        AnyView::new(view! { <!number {n}> }),
+    ];
+
+    view! { <div>{ for widgets }</div> }
+}
+
+#[wasm_bindgen_test]
+fn list_of_any_views() {
+    let mut list = kobold::test::render(view! { <!widgets n={1}> });
+
+    list.update(view! { <!widgets n={2}> });
+
+    assert_eq!(list.html(), "<div><p>a</p><b>2</b></div>");
+}
@@ -0,0 +1,91 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Two-way sync of input values with state.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlInputElement;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn name_input() -> impl View {
+    stateful(String::new, |name| {
+        view! {
+            <input value={name.as_str()} oninput={name.bind_input(|name, value, _| *name = value)}>
+            <button onclick={do name.clear()}>"Clear"</button>
+        }
+    })
+}
+
+fn input() -> web_sys::Event {
+    web_sys::Event::new("input").unwrap()
+}
+
+#[wasm_bindgen_test]
+fn typing_keeps_cursor() {
+    let form = kobold::test::render(view! { <!name_input> });
+    let input_el = form.query("input").unwrap();
+    let input_el = input_el.unchecked_ref::<HtmlInputElement>();
+
+    input_el.focus().unwrap();
+
+    // Type quickly in the middle of the text, every keystroke renders the view
+    for (text, cursor) in [("ac", 1), ("abc", 2), ("abxc", 3), ("abxyc", 4)] {
+        input_el.set_value(text);
+        input_el.set_selection_range(cursor, cursor).unwrap();
+
+        form.dispatch("input", &input());
+
+        assert_eq!(input_el.value(), text);
+        assert_eq!(input_el.selection_start().unwrap(), Some(cursor));
+        assert_eq!(input_el.selection_end().unwrap(), Some(cursor));
+    }
+}
+
+#[wasm_bindgen_test]
+fn state_change_sets_value() {
+    let form = kobold::test::render(view! { <!name_input> });
+    let input_el = form.query("input").unwrap();
+    let input_el = input_el.unchecked_ref::<HtmlInputElement>();
+
+    input_el.set_value("Kobold");
+    form.dispatch("input", &input());
+
+    form.click("button");
+
+    assert_eq!(input_el.value(), "");
+}
+
+#[component]
+fn optional_input(value: Option<&'static str>) -> impl View {
+    view! { <input {value}> }
+}
+
+#[wasm_bindgen_test]
+fn none_value_clears_input() {
+    let mut form = kobold::test::render(view! { <!optional_input value={Some("Kobold")}> });
+    let input_el = form.query("input").unwrap();
+    let input_el = input_el.unchecked_ref::<HtmlInputElement>();
+
+    assert_eq!(input_el.value(), "Kobold");
+
+    form.update(view! { <!optional_input value={None}> });
+
+    assert_eq!(input_el.value(), "");
+    assert!(!input_el.has_attribute("value"));
+
+    form.update(view! { <!optional_input value={Some("Again")}> });
+
+    assert_eq!(input_el.value(), "Again");
+}
@@ -0,0 +1,141 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Reordering items of `for keyed` lists.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn items(keys: &'static [u32]) -> impl View {
+    view! {
+        <ul>{ for keyed keys.iter().map(|key| view! { <li key={*key}>{ *key }</li> }) }</ul>
+    }
+}
+
+#[component]
+fn terms(keys: &'static [u32]) -> impl View {
+    view! {
+        <dl>
+        {
+            for keyed keys.iter().map(|key| (*key, view! { <dt>{ *key }</dt><dd>"def "{ *key }</dd> }))
+        }
+        </dl>
+    }
+}
+
+fn html(keys: &[u32]) -> String {
+    let items: String = keys.iter().map(|key| format!("<li>{key}</li>")).collect();
+
+    format!("<ul>{items}</ul>")
+}
+
+/// Find the node of each key in `keys` among the `nodes` rendered for `before`
+/// and check that it's the same node that is now rendered at its position.
+fn assert_same_nodes(nodes: &[Element], before: &[u32], after: &[Element], keys: &[u32]) {
+    for (key, node) in keys.iter().zip(after) {
+        if let Some(pos) = before.iter().position(|k| k == key) {
+            assert!(
+                nodes[pos].is_same_node(Some(node)),
+                "node for key {key} was rebuilt"
+            );
+        }
+    }
+}
+
+macro_rules! check {
+    ($before:expr => $after:expr) => {{
+        let mut list = kobold::test::render(view! { <!items keys={$before}> });
+
+        assert_eq!(list.html(), html($before));
+
+        let nodes = list.query_all("li");
+
+        list.update(view! { <!items keys={$after}> });
+
+        assert_eq!(list.html(), html($after));
+        assert_same_nodes(&nodes, $before, &list.query_all("li"), $after);
+    }};
+}
+
+#[wasm_bindgen_test]
+fn inserts_at_front() {
+    check!(&[1, 2, 3] => &[0, 1, 2, 3]);
+}
+
+#[wasm_bindgen_test]
+fn inserts_in_middle() {
+    check!(&[1, 2, 3] => &[1, 4, 2, 5, 3]);
+}
+
+#[wasm_bindgen_test]
+fn removes() {
+    check!(&[1, 2, 3, 4] => &[1, 3]);
+    check!(&[1, 2, 3, 4] => &[2, 3, 4]);
+    check!(&[1, 2, 3, 4] => &[]);
+}
+
+#[wasm_bindgen_test]
+fn reverses() {
+    check!(&[1, 2, 3, 4, 5] => &[5, 4, 3, 2, 1]);
+}
+
+#[wasm_bindgen_test]
+fn swaps_two_items() {
+    check!(&[1, 2, 3, 4, 5] => &[1, 4, 3, 2, 5]);
+    check!(&[1, 2, 3, 4, 5] => &[5, 2, 3, 4, 1]);
+}
+
+#[wasm_bindgen_test]
+fn mixed_moves() {
+    check!(&[1, 2, 3, 4, 5, 6] => &[6, 2, 7, 5, 3, 1]);
+}
+
+#[wasm_bindgen_test]
+fn skips_duplicate_keys() {
+    let mut list = kobold::test::render(view! { <!items keys={&[1, 1, 2]}> });
+
+    assert_eq!(list.html(), html(&[1, 2]));
+
+    let nodes = list.query_all("li");
+
+    list.update(view! { <!items keys={&[2, 1, 2, 1]}> });
+
+    assert_eq!(list.html(), html(&[2, 1]));
+    assert_same_nodes(&nodes, &[1, 2], &list.query_all("li"), &[2, 1]);
+}
+
+#[wasm_bindgen_test]
+fn moves_fragment_items() {
+    let mut list = kobold::test::render(view! { <!terms keys={&[1, 2, 3]}> });
+
+    let before = [list.query_all("dt"), list.query_all("dd")];
+
+    list.update(view! { <!terms keys={&[3, 1, 2]}> });
+
+    assert_eq!(
+        list.html(),
+        "<dl><dt>3</dt><dd>def 3</dd><dt>1</dt><dd>def 1</dd><dt>2</dt><dd>def 2</dd></dl>"
+    );
+    assert_same_nodes(&before[0], &[1, 2, 3], &list.query_all("dt"), &[3, 1, 2]);
+    assert_same_nodes(&before[1], &[1, 2, 3], &list.query_all("dd"), &[3, 1, 2]);
+
+    list.update(view! { <!terms keys={&[2, 4, 1]}> });
+
+    assert_eq!(
+        list.html(),
+        "<dl><dt>2</dt><dd>def 2</dd><dt>4</dt><dd>def 4</dd><dt>1</dt><dd>def 1</dd></dl>"
+    );
+    assert_same_nodes(&before[0], &[1, 2, 3], &list.query_all("dt"), &[2, 4, 1]);
+}
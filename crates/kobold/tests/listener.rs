@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Assert that event handlers are created in JavaScript once, at build time.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use std::cell::Cell;
+use std::mem::MaybeUninit;
+use std::pin::pin;
+
+use kobold::event::{Listener, ListenerHandle};
+use kobold::internal::{In, Out};
+use kobold::prelude::*;
+use kobold::reexport::wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static HANDLERS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Listener wrapper counting how many times a JavaScript handler was made for it
+struct Counted<L>(L);
+
+struct CountedProduct<P> {
+    inner: P,
+}
+
+type Click = MouseEvent<HtmlElement>;
+
+impl<L> Listener<Click> for Counted<L>
+where
+    L: Listener<Click>,
+{
+    type Product = CountedProduct<L::Product>;
+
+    fn build(self, p: In<Self::Product>) -> Out<Self::Product> {
+        p.in_place(|p| unsafe {
+            kobold::init!(p.inner @ self.0.build(p));
+
+            Out::from_raw(p)
+        })
+    }
+
+    fn update(self, p: &mut Self::Product) {
+        self.0.update(&mut p.inner);
+    }
+}
+
+impl<P: ListenerHandle> ListenerHandle for CountedProduct<P> {
+    fn js_value(&mut self) -> JsValue {
+        HANDLERS.with(|count| count.set(count.get() + 1));
+
+        self.inner.js_value()
+    }
+}
+
+fn button(count: u32) -> impl View {
+    let onclick = Counted(move |_: Click| {
+        let _ = count;
+    });
+
+    view! {
+        <button {onclick}>"Clicked "{ count }" times"</button>
+    }
+}
+
+#[wasm_bindgen_test]
+fn event_handler_made_once() {
+    let product = pin!(MaybeUninit::uninit());
+    let mut product = In::pinned(product, |p| button(0).build(p));
+
+    for count in 1..10 {
+        button(count).update(&mut product);
+    }
+
+    assert_eq!(HANDLERS.with(Cell::get), 1);
+}
@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Skipping updates of unchanged items in `for memo` lists.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Renders `label` in every row, but only rows with a changed `version` are updated.
+#[component]
+fn rows(versions: &'static [u32], label: &'static str) -> impl View {
+    view! {
+        <ul>
+        {
+            for memo versions.iter().map(move |version| (*version, view! { <li>{ label }" v"{ *version }</li> }))
+        }
+        </ul>
+    }
+}
+
+#[wasm_bindgen_test]
+fn unchanged_guard_skips_update() {
+    let mut list = kobold::test::render(view! { <!rows versions={&[1, 1, 1]} label="a"> });
+
+    assert_eq!(
+        list.html(),
+        "<ul><li>a v1</li><li>a v1</li><li>a v1</li></ul>"
+    );
+
+    let first = list.query("li").unwrap();
+
+    list.update(view! { <!rows versions={&[1, 2, 1]} label="b"> });
+
+    // Only the second row had its guard changed
+    assert_eq!(
+        list.html(),
+        "<ul><li>a v1</li><li>b v2</li><li>a v1</li></ul>"
+    );
+    assert!(first.is_same_node(list.query("li").as_deref()));
+}
+
+#[wasm_bindgen_test]
+fn grows_and_shrinks() {
+    let mut list = kobold::test::render(view! { <!rows versions={&[1]} label="a"> });
+
+    list.update(view! { <!rows versions={&[1, 1, 1]} label="b"> });
+    assert_eq!(
+        list.html(),
+        "<ul><li>a v1</li><li>b v1</li><li>b v1</li></ul>"
+    );
+
+    list.update(view! { <!rows versions={&[2]} label="c"> });
+    assert_eq!(list.html(), "<ul><li>c v2</li></ul>");
+}
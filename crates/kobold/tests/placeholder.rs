@@ -0,0 +1,72 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Skeleton placeholders with `placeholder_while`.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::branching::placeholder_while;
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn profile(name: Option<&'static str>) -> impl View {
+    view! {
+        <div>
+            { placeholder_while(name.is_none(), view! { <p.skeleton /> }, move || view! { <p>{ name.unwrap() }</p> }) }
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+fn swaps_skeleton_for_content() {
+    let mut profile = kobold::test::render(view! { <!profile name={None}> });
+
+    assert_eq!(profile.html(), r#"<div><p class="skeleton"></p></div>"#);
+
+    profile.update(view! { <!profile name={Some("Alice")}> });
+    assert_eq!(profile.html(), "<div><p>Alice</p></div>");
+
+    profile.update(view! { <!profile name={None}> });
+    assert_eq!(profile.html(), r#"<div><p class="skeleton"></p></div>"#);
+}
+
+#[wasm_bindgen_test]
+fn reuses_built_products() {
+    let mut profile = kobold::test::render(view! { <!profile name={Some("Alice")}> });
+
+    let content = profile.query("p").unwrap();
+
+    profile.update(view! { <!profile name={None}> });
+
+    let skeleton = profile.query("p").unwrap();
+
+    profile.update(view! { <!profile name={Some("Bob")}> });
+    assert!(content.is_same_node(profile.query("p").as_deref()));
+    assert_eq!(profile.html(), "<div><p>Bob</p></div>");
+
+    profile.update(view! { <!profile name={None}> });
+    assert!(skeleton.is_same_node(profile.query("p").as_deref()));
+}
+
+#[wasm_bindgen_test]
+fn content_is_lazy() {
+    let mut profile = kobold::test::render(placeholder_while(true, "loading", || -> &str {
+        panic!("content rendered while loading")
+    }));
+
+    assert_eq!(profile.html(), "loading");
+
+    profile.update(placeholder_while(true, "still loading", || -> &str {
+        unreachable!()
+    }));
+    assert_eq!(profile.html(), "still loading");
+}
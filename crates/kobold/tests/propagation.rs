@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Stopping events from propagating to handlers on parent elements.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Default)]
+struct Clicks {
+    inner: u32,
+    outer: u32,
+}
+
+// Renders `inner / outer` click counts, the inner button is nested in the outer div
+#[component]
+fn nested(stop: bool, silent: bool) -> impl View {
+    stateful(Clicks::default, move |clicks| {
+        view! {
+            <p>{ clicks.inner }" / "{ clicks.outer }</p>
+            <div onclick={do |clicks| clicks.outer += 1}>
+                <button onclick={do move |clicks| {
+                    clicks.inner += 1;
+
+                    match (stop, silent) {
+                        (false, _) => Then::Render,
+                        (true, false) => Then::StopPropagation,
+                        (true, true) => Then::StopPropagationSilent,
+                    }
+                }}>"Inner"</button>
+            </div>
+        }
+    })
+}
+
+#[wasm_bindgen_test]
+fn propagates_by_default() {
+    let clicks = kobold::test::render(view! { <!nested stop={false} silent={false}> });
+
+    clicks.click("button");
+
+    assert_eq!(clicks.text_of("p").as_deref(), Some("1 / 1"));
+}
+
+#[wasm_bindgen_test]
+fn stop_propagation_skips_outer_handler() {
+    let clicks = kobold::test::render(view! { <!nested stop={true} silent={false}> });
+
+    clicks.click("button");
+    clicks.click("button");
+
+    assert_eq!(clicks.text_of("p").as_deref(), Some("2 / 0"));
+}
+
+#[wasm_bindgen_test]
+fn stop_propagation_silent_skips_outer_handler() {
+    let clicks = kobold::test::render(view! { <!nested stop={true} silent={true}> });
+
+    clicks.click("button");
+
+    // The outer handler always renders, so the view staying as it was means it never ran
+    assert_eq!(clicks.text_of("p").as_deref(), Some("0 / 0"));
+}
@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Two-way sync of `checked` inputs with state.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::prelude::*;
+use kobold::stateful::radio_group;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlInputElement;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Size {
+    Small,
+    Medium,
+    Large,
+}
+
+#[component]
+fn size_picker() -> impl View {
+    stateful(
+        || Size::Small,
+        |size| {
+            let options = [Size::Small, Size::Medium, Size::Large];
+
+            view! {
+                <div>
+                {
+                    for radio_group(size, options).map(|radio| view! {
+                        <input type="radio" name="size" checked={radio.checked()} onchange={radio.onchange()}>
+                    })
+                }
+                </div>
+                <button onclick={do *size = Size::Large}>"Large"</button>
+            }
+        },
+    )
+}
+
+#[component]
+fn toggle(on: bool) -> impl View {
+    view! { <input type="checkbox" checked={on}> }
+}
+
+fn checked(inputs: &[web_sys::Element]) -> Vec<bool> {
+    inputs
+        .iter()
+        .map(|input| input.unchecked_ref::<HtmlInputElement>().checked())
+        .collect()
+}
+
+fn change() -> web_sys::Event {
+    web_sys::Event::new("change").unwrap()
+}
+
+#[wasm_bindgen_test]
+fn state_selects_radio() {
+    let picker = kobold::test::render(view! { <!size_picker> });
+
+    assert_eq!(checked(&picker.query_all("input")), [true, false, false]);
+
+    picker.click("button");
+
+    assert_eq!(checked(&picker.query_all("input")), [false, false, true]);
+}
+
+#[wasm_bindgen_test]
+fn selecting_radio_updates_state() {
+    let picker = kobold::test::render(view! { <!size_picker> });
+
+    picker.dispatch("input:nth-child(2)", &change());
+
+    assert_eq!(checked(&picker.query_all("input")), [false, true, false]);
+}
+
+#[wasm_bindgen_test]
+fn checked_false_unchecks() {
+    let mut toggle = kobold::test::render(view! { <!toggle on={false}> });
+    let input = toggle.query("input").unwrap();
+
+    // Checked by the user, the view still renders `false`
+    input.unchecked_ref::<HtmlInputElement>().set_checked(true);
+
+    toggle.update(view! { <!toggle on={false}> });
+
+    assert_eq!(checked(&[input]), [false]);
+}
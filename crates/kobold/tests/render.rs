@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Exercise the `kobold::test` utilities.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::list::unbounded::ListStats;
+use kobold::list::List;
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn list(items: &'static [&'static str]) -> impl View {
+    view! {
+        <ul>{ for items.iter().map(|item| view! { <li>{ *item }</li> }) }</ul>
+    }
+}
+
+#[component]
+fn reversed(items: &'static [&'static str]) -> impl View {
+    view! {
+        <ul>{ for rev items.iter().map(|item| view! { <li>{ *item }</li> }) }</ul>
+    }
+}
+
+#[component]
+fn counter() -> impl View {
+    stateful(0_u32, |count| {
+        bind! { count:
+            let onclick = move |_| *count += 1;
+        }
+
+        view! {
+            <button {onclick}>"Clicked "{ count.get() }" times"</button>
+        }
+    })
+}
+
+#[wasm_bindgen_test]
+fn renders_and_updates() {
+    let mut list = kobold::test::render(view! { <!list items={&["a", "b"]}> });
+
+    assert_eq!(list.html(), "<ul><li>a</li><li>b</li></ul>");
+
+    list.update(view! { <!list items={&["c"]}> });
+
+    assert_eq!(list.text(), "c");
+    assert_eq!(list.query_all("li").len(), 1);
+}
+
+#[wasm_bindgen_test]
+fn grows_in_order() {
+    let mut list = kobold::test::render(view! { <!list items={&["a"]}> });
+
+    list.update(view! { <!list items={&["a", "b", "c", "d"]}> });
+    assert_eq!(
+        list.html(),
+        "<ul><li>a</li><li>b</li><li>c</li><li>d</li></ul>"
+    );
+
+    let mut list = kobold::test::render(view! { <!reversed items={&["a"]}> });
+
+    list.update(view! { <!reversed items={&["a", "b", "c", "d"]}> });
+    assert_eq!(
+        list.html(),
+        "<ul><li>d</li><li>c</li><li>b</li><li>a</li></ul>"
+    );
+}
+
+#[wasm_bindgen_test]
+fn counts_list_changes() {
+    let numbers = |n: u32| List::new((0..n).map(|n| view! { <p>{ n }</p> }));
+
+    let mut list = kobold::test::render(numbers(3));
+    assert_eq!(list.product().stats().built, 3);
+
+    list.update(numbers(1));
+    assert_eq!(
+        list.product().stats(),
+        ListStats {
+            updated: 1,
+            unmounted: 2,
+            ..ListStats::default()
+        }
+    );
+
+    list.update(numbers(4));
+    assert_eq!(
+        list.product().stats(),
+        ListStats {
+            updated: 3,
+            built: 1,
+            remounted: 2,
+            unmounted: 0,
+        }
+    );
+}
+
+#[wasm_bindgen_test]
+fn dispatches_events() {
+    let counter = kobold::test::render(view! { <!counter> });
+
+    counter.click("button");
+    counter.click("button");
+
+    assert_eq!(
+        counter.text_of("button").as_deref(),
+        Some("Clicked 2 times")
+    );
+}
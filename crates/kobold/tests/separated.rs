@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Lists with separators between items.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::list::List;
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn tags(tags: &'static [&'static str]) -> impl View {
+    let tags = tags.iter().map(|tag| view! { <b>{ *tag }</b> });
+
+    view! {
+        <p>{ List::new(tags).separated_by(|| view! { <i>","</i> }) }</p>
+    }
+}
+
+#[wasm_bindgen_test]
+fn separates_items() {
+    let list = kobold::test::render(view! { <!tags tags={&["a", "b", "c"]}> });
+
+    assert_eq!(
+        list.html(),
+        "<p><b>a</b><i>,</i><b>b</b><i>,</i><b>c</b></p>"
+    );
+}
+
+#[wasm_bindgen_test]
+fn grows_and_shrinks() {
+    let mut list = kobold::test::render(view! { <!tags tags={&["a"]}> });
+
+    assert_eq!(list.html(), "<p><b>a</b></p>");
+
+    list.update(view! { <!tags tags={&["a", "b"]}> });
+    assert_eq!(list.html(), "<p><b>a</b><i>,</i><b>b</b></p>");
+
+    list.update(view! { <!tags tags={&["x"]}> });
+    assert_eq!(list.html(), "<p><b>x</b></p>");
+
+    list.update(view! { <!tags tags={&[]}> });
+    assert_eq!(list.html(), "<p></p>");
+
+    list.update(view! { <!tags tags={&["a", "b", "c"]}> });
+    assert_eq!(
+        list.html(),
+        "<p><b>a</b><i>,</i><b>b</b><i>,</i><b>c</b></p>"
+    );
+}
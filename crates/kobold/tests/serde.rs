@@ -0,0 +1,32 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Run with `cargo test -p kobold --features serde`.
+#![cfg(feature = "serde")]
+
+use kobold::diff::{VString, Ver};
+
+#[test]
+fn ver_round_trip() {
+    let mut ver = Ver::new(String::from("Kobold"));
+
+    // Bump the version, only the value is serialized
+    ver.push('!');
+
+    let json = serde_json::to_string(&ver).unwrap();
+
+    assert_eq!(json, r#""Kobold!""#);
+
+    let de: Ver<String> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(de.as_str(), "Kobold!");
+}
+
+#[test]
+fn vstring_round_trip() {
+    let json = serde_json::to_string(&VString::from("Kobold")).unwrap();
+    let de: VString = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(&*de, "Kobold");
+}
@@ -0,0 +1,130 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Sharing a subtree between mount points with `shared` and `template`.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use kobold::dom::{shared, template, Shared};
+use kobold::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn badges<F, V>(badge: Shared<F>, n: usize) -> impl View
+where
+    F: Fn() -> V + 'static,
+    V: View,
+{
+    view! {
+        <div>{ for (0..n).map(move |_| badge.clone()) }</div>
+    }
+}
+
+#[wasm_bindgen_test]
+fn builds_once_and_clones() {
+    let builds = Rc::new(Cell::new(0));
+    let badge = shared({
+        let builds = builds.clone();
+
+        move || {
+            builds.set(builds.get() + 1);
+
+            view! { <b>"New"</b> }
+        }
+    });
+
+    let mut list = kobold::test::render(badges(badge.clone(), 3));
+
+    assert_eq!(list.html(), "<div><b>New</b><b>New</b><b>New</b></div>");
+    assert_eq!(builds.get(), 1);
+
+    let first = list.query("b").unwrap();
+
+    list.update(badges(badge.clone(), 3));
+
+    // Updates are no-ops, the same nodes stay mounted
+    assert!(list.query("b").unwrap() == first);
+    assert_eq!(list.query_all("b").len(), 3);
+    assert_eq!(builds.get(), 1);
+}
+
+#[wasm_bindgen_test]
+fn first_mount_keeps_listeners() {
+    let clicks = Rc::new(Cell::new(0));
+    let button = shared({
+        let clicks = clicks.clone();
+
+        move || {
+            let clicks = clicks.clone();
+
+            view! { <button onclick={move |_| clicks.set(clicks.get() + 1)}>"+"</button> }
+        }
+    });
+
+    let mut list = kobold::test::render(badges(button.clone(), 2));
+
+    list.click("button");
+    assert_eq!(clicks.get(), 1);
+
+    list.update(badges(button.clone(), 2));
+    list.click("button");
+    assert_eq!(clicks.get(), 2);
+
+    // Clones only share the markup
+    list.query_all("button")[1]
+        .unchecked_ref::<HtmlElement>()
+        .click();
+    assert_eq!(clicks.get(), 2);
+}
+
+thread_local! {
+    static CLICKS: Cell<u32> = const { Cell::new(0) };
+}
+
+#[component]
+fn icons(n: usize) -> impl View {
+    view! {
+        <div>
+        {
+            for (0..n).map(|_| template(|| view! {
+                <button onclick={|_| CLICKS.set(CLICKS.get() + 1)}>"+"</button>
+            }))
+        }
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+fn template_keeps_listeners_of_first_instance() {
+    let mut list = kobold::test::render(view! { <!icons n={3}> });
+
+    assert_eq!(
+        list.html(),
+        "<div><button>+</button><button>+</button><button>+</button></div>"
+    );
+
+    list.click("button");
+    assert_eq!(CLICKS.get(), 1);
+
+    list.update(view! { <!icons n={3}> });
+    list.click("button");
+    assert_eq!(CLICKS.get(), 2);
+}
+
+#[wasm_bindgen_test]
+#[should_panic(expected = "must render a single root element")]
+fn template_rejects_fragments() {
+    kobold::test::render(template(|| view! { <b>"a"</b><b>"b"</b> }));
+}
@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Views cloned from a cached element with the `#[template]` directive.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn cell(x: usize, y: usize) -> impl View {
+    view! {
+        #[template]
+        <td.cell data-x={x}><b>{ x }", "{ y }</b><i>"static"</i></td>
+    }
+}
+
+#[component]
+fn plain_cell(x: usize, y: usize) -> impl View {
+    view! {
+        <td.cell data-x={x}><b>{ x }", "{ y }</b><i>"static"</i></td>
+    }
+}
+
+#[component]
+fn grid(size: usize, template: bool) -> impl View + 'static {
+    view! {
+        <table>
+        {
+            for (0..size).map(move |y| view! {
+                <tr>
+                {
+                    for (0..size).map(move |x| match template {
+                        true => Branch2::A(view! { <!cell {x} {y}> }),
+                        false => Branch2::B(view! { <!plain_cell {x} {y}> }),
+                    })
+                }
+                </tr>
+            })
+        }
+        </table>
+    }
+}
+
+#[wasm_bindgen_test]
+fn renders_like_plain_view() {
+    let cells = kobold::test::render(view! { <!grid size={3} template={true}> });
+    let plain = kobold::test::render(view! { <!grid size={3} template={false}> });
+
+    assert_eq!(cells.html(), plain.html());
+    assert_eq!(
+        cells.query("td").unwrap().outer_html(),
+        r#"<td class="cell" data-x="0"><b>0, 0</b><i>static</i></td>"#
+    );
+}
+
+#[component]
+fn pair(x: usize) -> impl View {
+    view! {
+        <div>
+            <!cell {x} y={2}>
+            <!cell x={3} y={4}>
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+fn clones_are_independent() {
+    let mut cells = kobold::test::render(view! { <!pair x={1}> });
+
+    let first = cells.query("td").unwrap();
+
+    cells.update(view! { <!pair x={5}> });
+
+    assert!(first.is_same_node(cells.query("td").as_deref()));
+    assert_eq!(
+        cells.html(),
+        r#"<div><td class="cell" data-x="5"><b>5, 2</b><i>static</i></td><td class="cell" data-x="3"><b>3, 4</b><i>static</i></td></div>"#
+    );
+}
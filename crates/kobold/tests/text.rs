@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Updating text nodes.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn name(name: &'static Option<&'static str>) -> impl View {
+    view! { <p>{ name }</p> }
+}
+
+#[component]
+fn owned_name(name: &'static Option<String>) -> impl View {
+    view! { <p>{ name }</p> }
+}
+
+#[wasm_bindgen_test]
+fn optional_text_reuses_text_node() {
+    let mut name = kobold::test::render(view! { <!name name={&Some("Alice")}> });
+
+    assert_eq!(name.html(), "<p>Alice</p>");
+
+    let text = name.query("p").unwrap().first_child().unwrap();
+
+    name.update(view! { <!name name={&None}> });
+    assert_eq!(name.html(), "<p></p>");
+    assert!(text.is_same_node(name.query("p").unwrap().first_child().as_ref()));
+
+    name.update(view! { <!name name={&Some("Bob")}> });
+    assert_eq!(name.html(), "<p>Bob</p>");
+    assert!(text.is_same_node(name.query("p").unwrap().first_child().as_ref()));
+}
+
+#[wasm_bindgen_test]
+fn optional_owned_text_reuses_text_node() {
+    let alice: &'static Option<String> = Box::leak(Box::new(Some("Alice".to_owned())));
+
+    let mut name = kobold::test::render(view! { <!owned_name name={&None}> });
+
+    assert_eq!(name.html(), "<p></p>");
+
+    let text = name.query("p").unwrap().first_child().unwrap();
+
+    name.update(view! { <!owned_name name={alice}> });
+    assert_eq!(name.html(), "<p>Alice</p>");
+    assert!(text.is_same_node(name.query("p").unwrap().first_child().as_ref()));
+}
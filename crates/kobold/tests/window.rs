@@ -0,0 +1,108 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Moving the window of a `window` list.
+//!
+//! Views can only be built in the browser, run these with:
+//!
+//! ```text
+//! wasm-pack test --headless --firefox crates/kobold --features test
+//! ```
+#![cfg(all(target_arch = "wasm32", feature = "test"))]
+
+use kobold::list::Windowed;
+use kobold::prelude::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn rows(count: usize, offset: usize) -> impl View {
+    view! {
+        <ul>{ for (0..count).map(|row| view! { <li>{ row }</li> }).window(offset, 4) }</ul>
+    }
+}
+
+fn html(rows: std::ops::Range<usize>) -> String {
+    let items: String = rows.map(|row| format!("<li>{row}</li>")).collect();
+
+    format!("<ul>{items}</ul>")
+}
+
+/// Move the window to `offset`, checking that rows that were rendered before
+/// the update keep their nodes, returns the number of rows kept.
+macro_rules! slide {
+    ($list:ident, $count:expr, $offset:expr) => {{
+        let before = $list.query_all("li");
+
+        $list.update(view! { <!rows count={$count} offset={$offset}> });
+
+        let mut kept = 0;
+
+        for node in $list.query_all("li") {
+            let text = node.text_content();
+
+            if let Some(old) = before.iter().find(|old| old.text_content() == text) {
+                assert!(old.is_same_node(Some(&node)), "row {text:?} was rebuilt");
+                kept += 1;
+            }
+        }
+
+        kept
+    }};
+}
+
+#[wasm_bindgen_test]
+fn slides_forward() {
+    let mut list = kobold::test::render(view! { <!rows count={20} offset={0}> });
+
+    assert_eq!(list.html(), html(0..4));
+
+    assert_eq!(slide!(list, 20, 1), 3);
+    assert_eq!(list.html(), html(1..5));
+
+    assert_eq!(slide!(list, 20, 3), 2);
+    assert_eq!(list.html(), html(3..7));
+}
+
+#[wasm_bindgen_test]
+fn slides_backward() {
+    let mut list = kobold::test::render(view! { <!rows count={20} offset={10}> });
+
+    assert_eq!(slide!(list, 20, 9), 3);
+    assert_eq!(list.html(), html(9..13));
+
+    assert_eq!(slide!(list, 20, 6), 1);
+    assert_eq!(list.html(), html(6..10));
+}
+
+#[wasm_bindgen_test]
+fn jumps_to_disjoint_range() {
+    let mut list = kobold::test::render(view! { <!rows count={20} offset={0}> });
+
+    assert_eq!(slide!(list, 20, 12), 0);
+    assert_eq!(list.html(), html(12..16));
+
+    assert_eq!(slide!(list, 20, 2), 0);
+    assert_eq!(list.html(), html(2..6));
+}
+
+#[wasm_bindgen_test]
+fn source_shrinks_below_offset() {
+    let mut list = kobold::test::render(view! { <!rows count={20} offset={8}> });
+
+    // The window is cut short at the end of the source
+    assert_eq!(slide!(list, 10, 8), 2);
+    assert_eq!(list.html(), html(8..10));
+
+    assert_eq!(slide!(list, 5, 8), 0);
+    assert_eq!(list.html(), html(0..0));
+
+    assert_eq!(slide!(list, 20, 8), 0);
+    assert_eq!(list.html(), html(8..12));
+
+    // Moving back while the source is shorter than the new window
+    assert_eq!(slide!(list, 9, 6), 1);
+    assert_eq!(list.html(), html(6..9));
+}
@@ -10,17 +10,29 @@ use tokens::TokenStream;
 pub fn parse(stream: TokenStream) -> Result<TokenStream, ParseError> {
     let mut stream = stream.parse_stream();
 
-    let class = stream.expect(Lit)?;
+    let lit = stream.expect(Lit)?;
+
+    let class = lit.to_string();
+
+    if !class.starts_with('"') {
+        return Err(ParseError::new("Expected a string literal class name", lit));
+    }
 
-    let class = class.to_string();
     let class = &class[1..class.len() - 1];
 
+    validate(class).map_err(|msg| ParseError::new(msg, lit))?;
+
     stream.expect("if")?;
 
     let fn_name = crate::unique();
 
     let condition: TokenStream = stream.collect();
 
+    let static_class = call("::kobold::attribute::StaticClass::new", ("t,", condition));
+
+    #[cfg(feature = "ssr")]
+    let static_class = (static_class, call(".with_class", string(class))).tokenize();
+
     let tokens = block((format_args!("\
         use ::kobold::reexport::wasm_bindgen;\
         use wasm_bindgen::prelude::wasm_bindgen;\
@@ -30,10 +42,28 @@ pub fn parse(stream: TokenStream) -> Result<TokenStream, ParseError> {
             #[wasm_bindgen(js_name = \"{fn_name}\")]\
             pub fn t(node: &::kobold::reexport::web_sys::Node, on: bool);\
         }}"),
-        call("::kobold::attribute::StaticClass::new", ("t,", condition)),
+        static_class,
     )).tokenize();
 
     // panic!("tokens: {}", tokens);
 
     Ok(tokens)
 }
+
+/// Literal class names are toggled on their own, so whitespace would silently
+/// produce multiple classes, and quotes or escapes would break the generated JavaScript.
+fn validate(class: &str) -> Result<(), &'static str> {
+    if class.is_empty() {
+        return Err("Class name can't be empty");
+    }
+
+    if class.contains(char::is_whitespace) {
+        return Err("Class name can't contain whitespace, use a separate `class!` for each class");
+    }
+
+    if class.contains(['"', '\\']) {
+        return Err("Class name can't contain quotes or backslashes");
+    }
+
+    Ok(())
+}
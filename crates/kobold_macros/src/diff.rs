@@ -0,0 +1,427 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+use tokens::{Delimiter, Group, Ident, Spacing, TokenStream, TokenTree};
+
+use crate::parse::prelude::*;
+use crate::parse::Pattern;
+use crate::tokenize::TokenStreamExt;
+
+struct Field {
+    name: Option<Ident>,
+    ty: TokenStream,
+}
+
+enum Fields {
+    Named(Vec<Field>),
+    Tuple(Vec<Field>),
+    Unit,
+}
+
+struct Variant {
+    name: Ident,
+    fields: Fields,
+}
+
+pub fn derive(stream: TokenStream) -> Result<TokenStream, ParseError> {
+    let mut stream = stream.parse_stream();
+
+    skip_attributes(&mut stream)?;
+    skip_visibility(&mut stream);
+
+    let is_enum = if stream.allow_consume("enum").is_some() {
+        true
+    } else if stream.allow_consume("struct").is_some() {
+        false
+    } else {
+        return Err(ParseError::new(
+            "Diff can only be derived for structs and enums",
+            stream.next(),
+        ));
+    };
+
+    let name: Ident = stream.parse()?;
+    let lifetimes = parse_lifetimes(&mut stream)?;
+
+    if let Some(tt) = stream.allow_consume("where") {
+        return Err(ParseError::new(
+            "Diff can't be derived for types with where clauses",
+            tt,
+        ));
+    }
+
+    let mut out = String::new();
+
+    let generics = match lifetimes.is_empty() {
+        true => String::new(),
+        false => {
+            let lifetimes = lifetimes.iter().map(|lt| format!("'{lt}"));
+
+            format!("<{}>", lifetimes.collect::<Vec<_>>().join(","))
+        }
+    };
+
+    let _ = write!(
+        out,
+        "const _: () = {{ impl{generics} ::kobold::diff::Diff for {name}{generics} {{"
+    );
+
+    if is_enum {
+        let body: Group = stream.parse()?;
+        let variants = parse_variants(body.stream())?;
+
+        write_enum(&mut out, &name, &variants, &lifetimes);
+    } else {
+        let fields = match stream.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                Fields::Named(parse_fields(group.stream(), true)?)
+            }
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                Fields::Tuple(parse_fields(group.stream(), false)?)
+            }
+            _ => Fields::Unit,
+        };
+
+        write_struct(&mut out, &name, &fields, &lifetimes);
+    }
+
+    Ok(TokenStream::from_str(&out).unwrap())
+}
+
+fn write_enum(out: &mut String, name: &Ident, variants: &[Variant], lifetimes: &[Ident]) {
+    // Fieldless enums are their own memo, only the discriminant is compared
+    if variants.iter().all(|v| matches!(v.fields, Fields::Unit)) {
+        out.push_str(
+            "type Memo = Self;\
+            fn into_memo(self) -> Self { self }\
+            fn diff(self, memo: &mut Self) -> bool {\
+                if ::std::mem::discriminant(&self) != ::std::mem::discriminant(memo) {\
+                    *memo = self;\
+                    true\
+                } else {\
+                    false\
+                }\
+            }\
+            }",
+        );
+
+        write_memo_end(out);
+        return;
+    }
+
+    out.push_str("type Memo = Memo; fn into_memo(self) -> Memo { match self {");
+
+    for variant in variants {
+        let this = pattern(&format!("{name}::{}", variant.name), &variant.fields, "f");
+        let memo = into_memo(&format!("Memo::{}", variant.name), &variant.fields);
+
+        let _ = write!(out, "{this} => {memo},");
+    }
+
+    out.push_str("} } fn diff(self, memo: &mut Memo) -> bool { match (self, memo) {");
+
+    for variant in variants {
+        let this = pattern(&format!("{name}::{}", variant.name), &variant.fields, "f");
+        let memo = pattern(&format!("Memo::{}", variant.name), &variant.fields, "m");
+        let diff = diff_fields(&variant.fields);
+
+        let _ = write!(out, "({this}, {memo}) => {diff},");
+    }
+
+    if variants.len() > 1 {
+        out.push_str(
+            "(this, memo) => {\
+                *memo = ::kobold::diff::Diff::into_memo(this);\
+                true\
+            }",
+        );
+    }
+
+    out.push_str("} } } pub enum Memo {");
+
+    for variant in variants {
+        let _ = write!(out, "{}", variant.name);
+        write_memo_fields(out, &variant.fields, lifetimes);
+        out.push(',');
+    }
+
+    out.push('}');
+
+    write_memo_end(out);
+}
+
+fn write_struct(out: &mut String, name: &Ident, fields: &Fields, lifetimes: &[Ident]) {
+    let this = pattern(&name.to_string(), fields, "f");
+    let memo = into_memo("Memo", fields);
+    let memo_pattern = pattern("Memo", fields, "m");
+    let diff = diff_fields(fields);
+
+    let _ = write!(
+        out,
+        "type Memo = Memo;\
+        fn into_memo(self) -> Memo {{ let {this} = self; {memo} }}\
+        fn diff(self, memo: &mut Memo) -> bool {{\
+            let {this} = self;\
+            let {memo_pattern} = memo;\
+            {diff}\
+        }}\
+        }} pub struct Memo"
+    );
+
+    write_memo_fields(out, fields, lifetimes);
+
+    if !matches!(fields, Fields::Named(_)) {
+        out.push(';');
+    }
+
+    write_memo_end(out);
+}
+
+fn write_memo_end(out: &mut String) {
+    out.push_str("};");
+}
+
+fn write_memo_fields(out: &mut String, fields: &Fields, lifetimes: &[Ident]) {
+    let (open, close, list) = match fields {
+        Fields::Named(list) => ('{', '}', list),
+        Fields::Tuple(list) => ('(', ')', list),
+        Fields::Unit => return,
+    };
+
+    out.push(open);
+
+    for field in list {
+        if let Some(name) = &field.name {
+            let _ = write!(out, "{name}:");
+        }
+
+        let ty = static_lifetimes(field.ty.clone(), lifetimes);
+
+        let _ = write!(out, "<{ty} as ::kobold::diff::Diff>::Memo,");
+    }
+
+    out.push(close);
+}
+
+/// Pattern binding all fields to `{prefix}{index}`.
+fn pattern(path: &str, fields: &Fields, prefix: &str) -> String {
+    match fields {
+        Fields::Named(list) => {
+            let bindings = list.iter().enumerate().map(|(i, field)| {
+                let name = field.name.as_ref().unwrap();
+
+                format!("{name}: {prefix}{i}")
+            });
+
+            format!("{path} {{ {} }}", bindings.collect::<Vec<_>>().join(","))
+        }
+        Fields::Tuple(list) => {
+            let bindings = (0..list.len()).map(|i| format!("{prefix}{i}"));
+
+            format!("{path}({})", bindings.collect::<Vec<_>>().join(","))
+        }
+        Fields::Unit => path.to_string(),
+    }
+}
+
+fn into_memo(path: &str, fields: &Fields) -> String {
+    let memo = |i| format!("::kobold::diff::Diff::into_memo(f{i})");
+
+    match fields {
+        Fields::Named(list) => {
+            let values = list.iter().enumerate().map(|(i, field)| {
+                let name = field.name.as_ref().unwrap();
+
+                format!("{name}: {}", memo(i))
+            });
+
+            format!("{path} {{ {} }}", values.collect::<Vec<_>>().join(","))
+        }
+        Fields::Tuple(list) => {
+            let values = (0..list.len()).map(memo);
+
+            format!("{path}({})", values.collect::<Vec<_>>().join(","))
+        }
+        Fields::Unit => path.to_string(),
+    }
+}
+
+/// Diff all fields, without short-circuiting so that every memo is updated.
+fn diff_fields(fields: &Fields) -> String {
+    let len = match fields {
+        Fields::Named(list) | Fields::Tuple(list) => list.len(),
+        Fields::Unit => 0,
+    };
+
+    let mut out = String::from("false");
+
+    for i in 0..len {
+        let _ = write!(out, " | ::kobold::diff::Diff::diff(f{i}, m{i})");
+    }
+
+    out
+}
+
+/// Replace the lifetimes of the type with `'static`, memos are always `'static`.
+fn static_lifetimes(stream: TokenStream, lifetimes: &[Ident]) -> TokenStream {
+    let mut after_quote = false;
+
+    stream
+        .into_iter()
+        .map(|tt| {
+            let tt = match tt {
+                TokenTree::Ident(ident)
+                    if after_quote
+                        && ident.with_str(|name| lifetimes.iter().any(|lt| lt.eq_str(name))) =>
+                {
+                    TokenTree::Ident(Ident::new("static", ident.span()))
+                }
+                TokenTree::Group(group) => {
+                    let stream = static_lifetimes(group.stream(), lifetimes);
+
+                    TokenTree::Group(Group::new(group.delimiter(), stream))
+                }
+                tt => tt,
+            };
+
+            after_quote = matches!(&tt, TokenTree::Punct(punct) if punct.as_char() == '\'');
+
+            tt
+        })
+        .collect()
+}
+
+fn skip_attributes(stream: &mut ParseStream) -> Result<(), ParseError> {
+    while stream.allow_consume('#').is_some() {
+        stream.expect('[')?;
+    }
+
+    Ok(())
+}
+
+fn skip_visibility(stream: &mut ParseStream) {
+    if stream.allow_consume("pub").is_some() {
+        stream.allow_consume('(');
+    }
+}
+
+fn parse_lifetimes(stream: &mut ParseStream) -> Result<Vec<Ident>, ParseError> {
+    let mut lifetimes = Vec::new();
+
+    if stream.allow_consume('<').is_none() {
+        return Ok(lifetimes);
+    }
+
+    loop {
+        if stream.allow_consume('>').is_some() {
+            return Ok(lifetimes);
+        }
+
+        if stream.allow_consume('\'').is_none() {
+            return Err(ParseError::new(
+                "Diff can only be derived for types generic over lifetimes",
+                stream.next(),
+            ));
+        }
+
+        lifetimes.push(stream.parse()?);
+
+        if stream.allow_consume(',').is_none() {
+            stream.expect('>')?;
+
+            return Ok(lifetimes);
+        }
+    }
+}
+
+fn parse_variants(stream: TokenStream) -> Result<Vec<Variant>, ParseError> {
+    let mut stream = stream.parse_stream();
+    let mut variants = Vec::new();
+
+    while !stream.end() {
+        skip_attributes(&mut stream)?;
+
+        let name: Ident = stream.parse()?;
+
+        let fields = match stream.peek() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                Fields::Named(parse_fields(group.stream(), true)?)
+            }
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                Fields::Tuple(parse_fields(group.stream(), false)?)
+            }
+            _ => Fields::Unit,
+        };
+
+        if !matches!(fields, Fields::Unit) {
+            stream.next();
+        }
+
+        // Skip explicit discriminant
+        if stream.allow_consume('=').is_some() {
+            while stream.next_if(|tt| !','.matches(tt)).is_some() {}
+        }
+
+        stream.allow_consume(',');
+
+        variants.push(Variant { name, fields });
+    }
+
+    Ok(variants)
+}
+
+fn parse_fields(stream: TokenStream, named: bool) -> Result<Vec<Field>, ParseError> {
+    let mut stream = stream.parse_stream();
+    let mut fields = Vec::new();
+
+    while !stream.end() {
+        skip_attributes(&mut stream)?;
+        skip_visibility(&mut stream);
+
+        let name = match named {
+            true => {
+                let name: Ident = stream.parse()?;
+
+                stream.expect(':')?;
+
+                Some(name)
+            }
+            false => None,
+        };
+
+        let mut ty = Vec::new();
+        let mut depth = 0_usize;
+        let mut after_dash = false;
+
+        while let Some(tt) = stream.peek() {
+            if let TokenTree::Punct(punct) = tt {
+                match punct.as_char() {
+                    ',' if depth == 0 => break,
+                    '<' => depth += 1,
+                    // Don't confuse `->` with the end of generics
+                    '>' if !after_dash => depth = depth.saturating_sub(1),
+                    _ => (),
+                }
+
+                after_dash = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+            } else {
+                after_dash = false;
+            }
+
+            ty.extend(stream.next());
+        }
+
+        stream.allow_consume(',');
+
+        fields.push(Field {
+            name,
+            ty: ty.into_iter().collect(),
+        });
+    }
+
+    Ok(fields)
+}
@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use tokens::{Delimiter, Ident, Literal, Span, TokenStream, TokenTree};
+use tokens::{Delimiter, Ident, Literal, Spacing, Span, TokenStream, TokenTree};
 
 use crate::parse::prelude::*;
 use crate::syntax::CssLabel;
@@ -11,10 +11,12 @@ use crate::tokenize::prelude::*;
 mod els;
 mod expression;
 mod shallow;
+mod style;
 
 pub use els::ElementTag;
 pub use expression::Expression;
 pub use shallow::{IsClosing, ShallowNode, ShallowNodeIter, ShallowStream, TagName, TagNesting};
+pub use style::take_style;
 
 pub fn parse(tokens: TokenStream) -> Result<Vec<Node>, ParseError> {
     let mut stream = tokens.parse_stream().into_shallow_stream();
@@ -30,6 +32,102 @@ pub fn parse(tokens: TokenStream) -> Result<Vec<Node>, ParseError> {
     Ok(nodes)
 }
 
+/// Strip a leading `#[template]` directive from the body of a `view!`, returning
+/// whether it was present.
+pub fn take_template(tokens: TokenStream) -> Result<(bool, TokenStream), ParseError> {
+    let mut stream = tokens.parse_stream();
+
+    let Some(hash) = stream.allow_consume('#') else {
+        return Ok((false, stream.collect()));
+    };
+
+    let directive = match stream.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => group,
+        _ => return Err(ParseError::new("Expected a directive: `#[template]`", hash)),
+    };
+
+    let mut inner = directive.stream().parse_stream();
+
+    match (inner.next(), inner.next()) {
+        (Some(TokenTree::Ident(ident)), None) if ident.eq_str("template") => {
+            Ok((true, stream.collect()))
+        }
+        _ => Err(ParseError::new(
+            "Unknown directive, allowed: `#[template]`",
+            directive.span(),
+        )),
+    }
+}
+
+/// Views with the `#[template]` directive are cloned from a single cached element.
+pub fn check_template(nodes: &[Node]) -> Result<(), ParseError> {
+    match nodes {
+        [Node::HtmlElement(_)] => Ok(()),
+        _ => Err(ParseError::new(
+            "Views with the `#[template]` directive must have a single root element",
+            Span::call_site(),
+        )),
+    }
+}
+
+/// Remove the `key` attribute from the root element or component, returning its value.
+///
+/// The key is only meaningful for a single root, where it turns the view into
+/// an item of a `for keyed` list. On components `key` is reserved and never
+/// passed on as a prop.
+pub fn take_key(nodes: &mut [Node]) -> Result<Option<TokenStream>, ParseError> {
+    let roots = nodes.len();
+    let mut key = None;
+
+    for node in nodes.iter_mut() {
+        let (value, span) = match node {
+            Node::HtmlElement(el) => {
+                let Some(idx) = el
+                    .attributes
+                    .iter()
+                    .position(|attr| attr.name.label == "key")
+                else {
+                    continue;
+                };
+
+                let attr = el.attributes.remove(idx);
+                let span = attr.name.ident.span();
+
+                let value = match attr.value {
+                    AttributeValue::Literal(lit) => lit.tokenize(),
+                    AttributeValue::Expression(expr) => expr.stream,
+                    AttributeValue::Boolean(_) => {
+                        return Err(ParseError::new("The key attribute requires a value", span))
+                    }
+                };
+
+                (value, span)
+            }
+            Node::Component(com) => {
+                let Some(idx) = com.props.iter().position(|prop| prop.name.eq_str("key")) else {
+                    continue;
+                };
+
+                let prop = com.props.remove(idx);
+
+                (prop.expr.stream, prop.name.span())
+            }
+            _ => continue,
+        };
+
+        if roots > 1 {
+            return Err(ParseError::new(
+                "The key attribute is only allowed on a single root element",
+                span,
+            ));
+        }
+
+        key = Some(value);
+    }
+
+    Ok(key)
+}
+
 #[derive(Debug)]
 pub enum Node {
     HtmlElement(HtmlElement),
@@ -44,6 +142,8 @@ pub struct Component {
     pub span: Span,
     pub path: TokenStream,
     pub generics: Option<TokenStream>,
+    /// Expression spread with `{..props}` before all other props
+    pub spread: Option<TokenStream>,
     pub props: Vec<Property>,
     pub children: Option<Vec<Node>>,
 }
@@ -125,7 +225,16 @@ impl Node {
                 let mut content = tag.content.parse_stream();
                 let mut props = Vec::new();
 
+                let spread = parse_spread(&mut content)?;
+
                 while !content.end() {
+                    if let Some(tt) = content.peek().filter(|tt| is_spread(tt)) {
+                        return Err(ParseError::new(
+                            "Spread props must be the first property of a component",
+                            tt.span(),
+                        ));
+                    }
+
                     props.push(content.parse()?);
                 }
 
@@ -142,6 +251,7 @@ impl Node {
                     span,
                     path,
                     generics,
+                    spread,
                     props,
                     children,
                 }));
@@ -157,7 +267,13 @@ impl Node {
 
                 loop {
                     if content.allow_consume('.').is_some() {
-                        classes.push(content.parse()?);
+                        let class = content.parse()?;
+
+                        if content.allow_consume('?').is_some() {
+                            classes.push(conditional_class(class, &mut content)?);
+                        } else {
+                            classes.push(class);
+                        }
                     } else if let Some(hash) = content.allow_consume('#') {
                         let name = CssLabel {
                             label: "id".into(),
@@ -242,6 +358,68 @@ enum Children {
     Implicit(Vec<Node>),
 }
 
+/// Turn `.class?={condition}` shorthand into a `class!("class" if condition)` expression,
+/// the condition only ever applies to the class immediately preceding it.
+fn conditional_class(class: CssValue, stream: &mut ParseStream) -> Result<CssValue, ParseError> {
+    let CssValue::Literal(class) = class else {
+        return Err(ParseError::new(
+            "Only literal class names can be conditional, use class! instead",
+            stream.next(),
+        ));
+    };
+
+    stream.expect('=')?;
+
+    let condition = match stream.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group,
+        tt => {
+            return Err(ParseError::new(
+                "Expected a {condition} for the conditional class",
+                tt,
+            ))
+        }
+    };
+
+    Ok(CssValue::Expression(Expression {
+        stream: call("::kobold::class!", (class, ident("if"), condition.stream())),
+        span: condition.span(),
+        is_static: false,
+    }))
+}
+
+/// Check if a token is a `{..props}` spread expression
+fn is_spread(tt: &TokenTree) -> bool {
+    match tt {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+            let mut inner = group.stream().parse_stream();
+
+            inner.allow_consume(('.', Spacing::Joint)).is_some() && inner.allow('.')
+        }
+        _ => false,
+    }
+}
+
+fn parse_spread(stream: &mut ParseStream) -> Result<Option<TokenStream>, ParseError> {
+    let group = match stream.next_if(is_spread) {
+        Some(TokenTree::Group(group)) => group,
+        _ => return Ok(None),
+    };
+
+    let mut inner = group.stream().parse_stream();
+
+    inner.next();
+    inner.next();
+
+    if inner.end() {
+        return Err(ParseError::new(
+            "Expected an expression to spread",
+            group.span(),
+        ));
+    }
+
+    Ok(Some(inner.collect()))
+}
+
 impl Parse for Property {
     fn parse(stream: &mut ParseStream) -> Result<Self, ParseError> {
         // Allow expression shorthand
@@ -284,26 +462,6 @@ impl Parse for Property {
     }
 }
 
-impl CssValue {
-    pub fn as_literal(&self) -> Option<&Literal> {
-        match self {
-            CssValue::Literal(lit) => Some(lit),
-            CssValue::Expression(_) => None,
-        }
-    }
-
-    pub fn is_literal(&self) -> bool {
-        !self.is_expression()
-    }
-
-    pub fn is_expression(&self) -> bool {
-        match self {
-            CssValue::Literal(_) => false,
-            CssValue::Expression(_) => true,
-        }
-    }
-}
-
 impl Parse for CssValue {
     fn parse(stream: &mut ParseStream) -> Result<Self, ParseError> {
         if let Some(expr) = stream.allow_consume('{') {
@@ -369,7 +527,29 @@ impl Parse for Attribute {
             ));
         }
 
-        let name = stream.parse()?;
+        let mut name: CssLabel = stream.parse()?;
+
+        // Event modifiers, `onclick.once={...}`
+        while stream.allow_consume('.').is_some() {
+            let modifier: Ident = stream.parse()?;
+
+            if !name.label.starts_with("on") {
+                return Err(ParseError::new(
+                    "Modifiers are only allowed on event attributes",
+                    modifier.span(),
+                ));
+            }
+
+            if !modifier.one_of(["once", "capture", "passive"]) {
+                return Err(ParseError::new(
+                    "Unknown event modifier, allowed: `once`, `capture`, or `passive`",
+                    modifier.span(),
+                ));
+            }
+
+            name.label.push('.');
+            name.label.push_str(&modifier.to_string());
+        }
 
         stream.expect('=')?;
 
@@ -4,9 +4,9 @@
 
 use std::fmt::{self, Debug};
 
-use tokens::{Group, Ident, Span, TokenStream, TokenTree};
+use tokens::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
 
-use crate::dom::{IteratorExt, Lit, Node, ParseError};
+use crate::dom::{IteratorExt, Lit, Node, ParseError, ParseStream};
 use crate::parse::IdentExt;
 use crate::tokenize::prelude::*;
 
@@ -53,6 +53,10 @@ impl TryFrom<Group> for Expression {
     fn try_from(group: Group) -> Result<Self, ParseError> {
         let mut stream = group.stream().parse_stream();
 
+        if let Some(expr) = if_const(&mut stream, group.span())? {
+            return Ok(expr);
+        }
+
         if let Some(TokenTree::Ident(ident)) = stream.peek() {
             let span = ident.span();
             let mut is_static = false;
@@ -90,8 +94,21 @@ impl TryFrom<Group> for Expression {
 
                         keyword = "for_bounded";
                         invoke = Some(("::<_, ", n, close).tokenize())
+                    } else if is_modifier(&stream, "keyed") {
+                        stream.next();
+
+                        keyword = "for_keyed";
+                    } else if is_modifier(&stream, "rev") {
+                        stream.next();
+
+                        keyword = "for_rev";
+                    } else if is_modifier(&stream, "memo") {
+                        stream.next();
+
+                        keyword = "for_memo";
                     }
                 }
+
                 let keyword = Ident::new_raw(keyword, span);
 
                 return Ok(Expression {
@@ -113,6 +130,65 @@ impl TryFrom<Group> for Expression {
     }
 }
 
+/// Parse `if const COND { ... }`, returns `None` for regular `if` expressions.
+fn if_const(stream: &mut ParseStream, span: Span) -> Result<Option<Expression>, ParseError> {
+    let mut ahead = stream.clone();
+
+    if ahead.allow_consume("if").is_none() || ahead.allow_consume("const").is_none() {
+        return Ok(None);
+    }
+
+    let mut cond = TokenStream::new();
+
+    let body = loop {
+        match ahead.next() {
+            Some(TokenTree::Group(body))
+                if body.delimiter() == Delimiter::Brace && !cond.is_empty() =>
+            {
+                break body;
+            }
+            Some(tt) => cond.write(tt),
+            None => {
+                return Err(ParseError::new(
+                    "Expected a block after the `if const` condition",
+                    span,
+                ))
+            }
+        }
+    };
+
+    if let Some(tt) = ahead.next() {
+        return Err(ParseError::new(
+            "`if const` can't have an `else` branch, use a runtime `if` instead",
+            tt,
+        ));
+    }
+
+    *stream = ahead;
+
+    Ok(Some(Expression {
+        stream: call(
+            ("::kobold::keywords::if_const::<", block(cond), ", _>"),
+            ("move ||", body),
+        ),
+        span,
+        is_static: false,
+    }))
+}
+
+/// Check if the stream starts with a `modifier` keyword rather than an expression
+/// using a variable or a function of the same name, as in `for keyed.iter()` or
+/// `for rev(items)`.
+fn is_modifier(stream: &ParseStream, modifier: &str) -> bool {
+    let mut ahead = stream.clone();
+
+    ahead.allow_consume(modifier).is_some()
+        && !ahead.end()
+        && !ahead.allow('.')
+        && !ahead.allow('?')
+        && !ahead.allow(Delimiter::Parenthesis)
+}
+
 impl From<&str> for Expression {
     fn from(code: &str) -> Self {
         Expression {
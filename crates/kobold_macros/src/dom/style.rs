@@ -0,0 +1,198 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Scoped `<style>` blocks at the root of a `view!`.
+
+use std::fmt::Write;
+use std::hash::Hasher;
+
+use fnv::FnvHasher;
+use tokens::{Ident, Literal};
+
+use crate::dom::{Attribute, AttributeValue, ElementTag, Node};
+use crate::gen::Transient;
+use crate::parse::prelude::*;
+use crate::syntax::CssLabel;
+
+pub struct ScopedStyle {
+    /// Deterministic id derived from the stylesheet, `k-` followed by 8 hex digits
+    id: String,
+    /// Stylesheet wrapped in an `@scope` rule
+    css: String,
+}
+
+/// Remove all `<style>` elements from the root of the view, scoping their CSS
+/// to the remaining root elements.
+pub fn take_style(nodes: &mut Vec<Node>) -> Result<Option<ScopedStyle>, ParseError> {
+    let mut css = String::new();
+    let mut span = None;
+
+    let mut idx = 0;
+
+    while idx < nodes.len() {
+        match &nodes[idx] {
+            Node::HtmlElement(el) if el.name == ElementTag::Style => (),
+            _ => {
+                idx += 1;
+                continue;
+            }
+        }
+
+        let Node::HtmlElement(el) = nodes.remove(idx) else {
+            unreachable!();
+        };
+
+        if let Some(attr) = el.attributes.first() {
+            return Err(ParseError::new(
+                "Scoped styles don't take attributes",
+                attr.name.ident.span(),
+            ));
+        }
+
+        for child in el.children.into_iter().flatten() {
+            let lit =
+                match child {
+                    Node::Text(lit) => lit,
+                    Node::Expression(expr) => return Err(ParseError::new(
+                        "Scoped styles must be static, use the style attribute for dynamic values",
+                        expr.span,
+                    )),
+                    _ => {
+                        return Err(ParseError::new(
+                            "Scoped styles can only contain string literals",
+                            el.span,
+                        ))
+                    }
+                };
+
+            let Some(text) = unescape(&lit.to_string()) else {
+                return Err(ParseError::new(
+                    "Scoped styles can only contain string literals",
+                    lit.span(),
+                ));
+            };
+
+            if text.contains("</") {
+                return Err(ParseError::new(
+                    "Scoped styles can't contain `</`",
+                    lit.span(),
+                ));
+            }
+
+            css.push_str(&text);
+            css.push('\n');
+        }
+
+        span.get_or_insert(el.span);
+    }
+
+    let Some(span) = span else {
+        return Ok(None);
+    };
+
+    let mut hasher = FnvHasher::default();
+
+    hasher.write(css.as_bytes());
+
+    let id = format!("k-{:08x}", hasher.finish() & 0xffff_ffff);
+    let label = format!("data-{id}");
+
+    let mut scoped = 0;
+
+    for node in nodes.iter_mut() {
+        if let Node::HtmlElement(el) = node {
+            el.attributes.push(Attribute {
+                name: CssLabel {
+                    label: label.clone(),
+                    ident: Ident::new("data", el.span),
+                },
+                value: AttributeValue::Literal(Literal::string("")),
+            });
+
+            scoped += 1;
+        }
+    }
+
+    if scoped == 0 {
+        return Err(ParseError::new(
+            "Scoped styles require an element at the root of the view",
+            span,
+        ));
+    }
+
+    let css = format!("@scope ([{label}]) {{\n{css}}}");
+
+    Ok(Some(ScopedStyle { id, css }))
+}
+
+impl ScopedStyle {
+    /// Append the stylesheet to the JS module, inserting it into the document once
+    /// when the module is loaded, and render it inline for SSR, once per render.
+    pub fn inject(self, transient: &mut Transient) {
+        let ScopedStyle { id, css } = self;
+        let text = Literal::string(&css);
+
+        let _ = write!(
+            transient.js.code,
+            "if(!document.getElementById(\"{id}\")){{\
+                \nlet s=document.createElement(\"style\");\
+                \ns.id=\"{id}\";\
+                \ns.textContent={text};\
+                \ndocument.head.appendChild(s);\
+            \n}}\n"
+        );
+
+        transient.html.style(id, text);
+    }
+}
+
+/// Contents of a string literal, `None` if the literal is not a string.
+fn unescape(lit: &str) -> Option<String> {
+    if let Some(raw) = lit.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let raw = &raw[hashes..raw.len() - hashes];
+
+        return Some(raw.strip_prefix('"')?.strip_suffix('"')?.to_string());
+    }
+
+    let mut chars = lit.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut out = String::new();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            '0' => out.push('\0'),
+            '\n' => {
+                // Line continuation, skip leading whitespace on the next line
+                let rest = chars.as_str().trim_start();
+
+                chars = rest.chars();
+            }
+            'x' => {
+                let hex = chars.as_str().get(..2)?;
+
+                out.push(u8::from_str_radix(hex, 16).ok()? as char);
+                chars.nth(1);
+            }
+            'u' => {
+                let rest = chars.as_str().strip_prefix('{')?;
+                let end = rest.find('}')?;
+                let code = u32::from_str_radix(&rest[..end], 16).ok()?;
+
+                out.push(char::from_u32(code)?);
+                chars = rest[end + 1..].chars();
+            }
+            c => out.push(c),
+        }
+    }
+
+    Some(out)
+}
@@ -4,7 +4,7 @@
 
 use std::fmt::Write;
 
-use tokens::{Group, Ident, TokenStream, TokenTree};
+use tokens::{Group, Ident, Span, TokenStream, TokenTree};
 
 use crate::parse::prelude::*;
 use crate::tokenize::prelude::*;
@@ -20,6 +20,8 @@ use generic_finder::GenericFinder;
 pub struct ComponentArgs {
     branching: Option<Ident>,
     children: Option<Ident>,
+    memo: Option<Ident>,
+    fallible: Option<(Ident, Option<TokenStream>)>,
     defaults: Vec<(Ident, Value)>,
 }
 
@@ -42,6 +44,10 @@ pub fn component(mut args: ComponentArgs, stream: TokenStream) -> Result<TokenSt
         component.render = scope.tokenize();
     }
 
+    if args.memo.is_some() {
+        component.memoize();
+    }
+
     Ok(component.tokenize())
 }
 
@@ -56,6 +62,8 @@ pub fn args(stream: TokenStream) -> Result<ComponentArgs, ParseError> {
     enum Token {
         Children,
         AutoBranch,
+        Memo,
+        Fallible,
         Default,
     }
 
@@ -68,8 +76,10 @@ pub fn args(stream: TokenStream) -> Result<ComponentArgs, ParseError> {
             ident.with_str(|s| match s {
                 "children" => Ok(Token::Children),
                 "auto_branch" => Ok(Token::AutoBranch),
+                "memo" => Ok(Token::Memo),
+                "fallible" => Ok(Token::Fallible),
                 _ => Err(ParseError::new(
-                    "Unknown attribute, allowed: `auto_branch`, `children`, or `<parameter>?`",
+                    "Unknown attribute, allowed: `auto_branch`, `children`, `fallible`, `memo`, or `<parameter>?`",
                     ident.span(),
                 )),
             })?
@@ -77,6 +87,16 @@ pub fn args(stream: TokenStream) -> Result<ComponentArgs, ParseError> {
 
         match token {
             Token::AutoBranch => args.branching = Some(ident),
+            Token::Memo => args.memo = Some(ident),
+            Token::Fallible => {
+                let handler = if stream.allow_consume(':').is_some() {
+                    Some(take_until_comma(&mut stream))
+                } else {
+                    None
+                };
+
+                args.fallible = Some((ident, handler));
+            }
             Token::Children => {
                 args.children = Some(ident);
 
@@ -86,17 +106,7 @@ pub fn args(stream: TokenStream) -> Result<ComponentArgs, ParseError> {
             }
             Token::Default => {
                 let value = if stream.allow_consume(':').is_some() {
-                    let mut value = TokenStream::new();
-
-                    while let Some(tt) = stream.peek() {
-                        if tt.is(',') {
-                            break;
-                        }
-
-                        value.extend(stream.next());
-                    }
-
-                    Value::Expr(value)
+                    Value::Expr(take_until_comma(&mut stream))
                 } else {
                     Value::Default
                 };
@@ -119,6 +129,20 @@ pub fn args(stream: TokenStream) -> Result<ComponentArgs, ParseError> {
     Ok(args)
 }
 
+fn take_until_comma(stream: &mut ParseStream) -> TokenStream {
+    let mut value = TokenStream::new();
+
+    while let Some(tt) = stream.peek() {
+        if tt.is(',') {
+            break;
+        }
+
+        value.extend(stream.next());
+    }
+
+    value
+}
+
 struct Function {
     r#fn: TokenTree,
     r#pub: Option<TokenStream>,
@@ -140,6 +164,15 @@ struct FnComponent {
     arguments: Vec<Argument>,
     ret: TokenStream,
     render: TokenStream,
+    fallible: Option<Fallible>,
+}
+
+/// Return type of a `#[component(fallible)]`
+struct Fallible {
+    /// The `Ok` type of the returned `Result`
+    ok: TokenStream,
+    /// Function rendering the error, if not using the default
+    handler: Option<TokenStream>,
 }
 
 impl FnComponent {
@@ -185,11 +218,34 @@ impl FnComponent {
             ));
         }
 
+        if args.memo.is_none() {
+            if let Some(span) = fun.arguments.iter().find_map(|arg| arg.skip_memo) {
+                return Err(ParseError::new(
+                    "`#[memo(skip)]` requires the component to be declared with `#[component(memo)]`",
+                    span,
+                ));
+            }
+        }
+
         let render = match fun.body {
             TokenTree::Group(group) => group.stream(),
             tt => tt.into(),
         };
 
+        let fallible = match args.fallible.take() {
+            Some((ident, handler)) => {
+                let ok = result_ok_type(&fun.r#return).ok_or_else(|| {
+                    ParseError::new(
+                        "Components marked as `fallible` must return a `Result<impl View, E>`",
+                        ident.span(),
+                    )
+                })?;
+
+                Some(Fallible { ok, handler })
+            }
+            None => None,
+        };
+
         let r#mod = Ident::new("mod", fun.r#fn.span());
 
         Ok(FnComponent {
@@ -202,14 +258,74 @@ impl FnComponent {
             arguments: fun.arguments,
             ret: fun.r#return,
             render,
+            fallible,
         })
     }
 }
 
+/// Find the `Ok` type in a `-> Result<Ok, Err>` return type.
+fn result_ok_type(ret: &TokenStream) -> Option<TokenStream> {
+    let mut stream = ret.clone().parse_stream();
+
+    stream.expect('-').ok()?;
+    stream.expect('>').ok()?;
+
+    // Skip the path up to the generics, e.g. `std::result::Result`
+    let mut last = None;
+
+    while stream.allow_consume('<').is_none() {
+        last = Some(stream.next()?);
+    }
+
+    if !last.map_ident(|ident| ident == "Result")? {
+        return None;
+    }
+
+    let mut ok = TokenStream::new();
+    let mut depth = 0;
+
+    for tt in stream {
+        if depth == 0 && tt.is(',') {
+            return Some(ok);
+        }
+        if tt.is('<') {
+            depth += 1;
+        }
+        if tt.is('>') {
+            depth -= 1;
+        }
+
+        ok.write(tt);
+    }
+
+    None
+}
+
+impl FnComponent {
+    /// Wrap the render body in a `fence` guarded by a tuple of all
+    /// parameters that aren't marked with `#[memo(skip)]`.
+    fn memoize(&mut self) {
+        let guard = each(
+            self.arguments
+                .iter()
+                .filter(|arg| arg.skip_memo.is_none())
+                .map(Argument::name),
+        );
+        let render = std::mem::take(&mut self.render);
+
+        self.render = call(
+            "::kobold::diff::fence",
+            (group('(', guard), ", move ||", block(render)),
+        );
+    }
+}
+
 struct Argument {
+    attrs: TokenStream,
     name: Ident,
     ty: TokenStream,
     default: Option<Value>,
+    skip_memo: Option<Span>,
 }
 
 impl Parse for Function {
@@ -237,7 +353,16 @@ impl Parse for Function {
             let mut stream = args.stream().parse_stream();
 
             while !stream.end() {
-                arguments.push(stream.parse()?);
+                arguments.push(stream.parse::<Argument>()?);
+            }
+
+            // `#[memo(skip)]` markers are consumed by the macro, rebuild
+            // the arguments so they don't end up in the generated function.
+            if arguments.iter().any(|arg| arg.skip_memo.is_some()) {
+                let mut rebuilt = group('(', each(arguments.iter().map(Argument::raw)));
+
+                rebuilt.set_span(args.span());
+                raw_args = Some(rebuilt);
             }
         }
 
@@ -273,6 +398,26 @@ impl Parse for Function {
 
 impl Parse for Argument {
     fn parse(stream: &mut ParseStream) -> Result<Self, ParseError> {
+        let mut attrs = TokenStream::new();
+        let mut skip_memo = None;
+
+        while let Some(hash) = stream.allow_consume('#') {
+            let attr: Group = stream.parse()?;
+            let mut inner = attr.stream().parse_stream();
+
+            if inner.allow_consume("memo").is_some() {
+                let arg: Group = inner.parse()?;
+
+                if !arg.stream().parse_stream().allow("skip") {
+                    return Err(ParseError::new("Expected `#[memo(skip)]`", arg.span()));
+                }
+
+                skip_memo = Some(hash.span());
+            } else {
+                attrs.write((hash, attr));
+            }
+        }
+
         let name = stream.parse()?;
 
         stream.expect(':')?;
@@ -280,9 +425,11 @@ impl Parse for Argument {
         let ty = stream.take_while(|token| !token.is(',')).collect();
 
         Ok(Argument {
+            attrs,
             name,
             ty,
             default: None,
+            skip_memo,
         })
     }
 }
@@ -322,18 +469,22 @@ impl Tokenize for FnComponent {
             ));
         };
 
+        let invoke = call(
+            ("super::", name),
+            each(self.arguments.iter().map(Argument::name)),
+        );
+
+        let (ret, invoke) = match self.fallible {
+            Some(fallible) => fallible.render(invoke),
+            None => (self.ret.clone(), invoke),
+        };
+
         let fn_render = (
             "pub fn render",
             self.generics.clone(),
             group('(', args),
-            self.ret.clone(),
-            block((
-                each(self.arguments.iter().map(Argument::maybe)),
-                call(
-                    ("super::", name),
-                    each(self.arguments.iter().map(Argument::name)),
-                ),
-            )),
+            ret,
+            block((each(self.arguments.iter().map(Argument::maybe)), invoke)),
         );
 
         let fn_props = (
@@ -379,6 +530,37 @@ impl Tokenize for FnComponent {
     }
 }
 
+impl Fallible {
+    /// Return type and body of the `render` function rendering
+    /// either the `Ok` view or the error.
+    fn render(self, invoke: TokenStream) -> (TokenStream, TokenStream) {
+        let ret = if self.ok.clone().parse_stream().allow("impl") {
+            ("->", self.ok).tokenize()
+        } else {
+            "-> impl ::kobold::View".tokenize()
+        };
+
+        let error = match self.handler {
+            Some(handler) => call(handler, "err"),
+            None => "::kobold::view! { <pre>{ ::std::string::ToString::to_string(&err) }</pre> }"
+                .tokenize(),
+        };
+
+        let body = (
+            "match",
+            invoke,
+            block((
+                "Ok(view) => ::kobold::branching::Branch2::A(view),",
+                "Err(err) => ::kobold::branching::Branch2::B",
+                group('(', error),
+                ',',
+            )),
+        );
+
+        (ret, body.tokenize())
+    }
+}
+
 impl Argument {
     fn ty(&self) -> impl Tokenize + '_ {
         tok_fn(|stream| {
@@ -398,6 +580,10 @@ impl Argument {
         (&self.name, ',')
     }
 
+    fn raw(&self) -> impl Tokenize + '_ {
+        (&self.attrs, &self.name, ':', &self.ty, ',')
+    }
+
     fn generic(&self) -> impl Tokenize + '_ {
         (&self.name, "= ::kobold::maybe::Undefined,")
     }
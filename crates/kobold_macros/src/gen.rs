@@ -14,10 +14,14 @@ use crate::tokenize::prelude::*;
 mod component;
 mod element;
 mod fragment;
+#[cfg_attr(not(feature = "ssr"), allow(dead_code))]
+mod ssr;
 mod transient;
 
+use element::Attr;
 pub use element::JsElement;
 pub use fragment::{append, JsFragment};
+pub use ssr::Html;
 pub use transient::{Anchor, Field, FieldKind, Hint, Transient};
 pub use transient::{JsArgument, JsFnName, JsFunction, JsString};
 
@@ -31,26 +35,40 @@ pub enum DomNode {
     Fragment(JsFragment),
 }
 
-pub fn generate(mut nodes: Vec<Node>) -> Transient {
-    let mut gen = Generator::default();
-
-    let dom_node = if nodes.len() == 1 {
-        nodes.remove(0).into_gen(&mut gen)
-    } else {
-        nodes.into_gen(&mut gen)
-    };
+pub fn generate(nodes: Vec<Node>) -> Transient {
+    Generator::default().generate(nodes)
+}
 
-    gen.hoist(dom_node);
-    gen.out
+/// Same as [`generate`], but every element constructor builds its nodes once and clones
+/// them on following calls, see `#[template]` in the `view!` docs.
+pub fn generate_template(nodes: Vec<Node>) -> Transient {
+    Generator {
+        template: true,
+        ..Generator::default()
+    }
+    .generate(nodes)
 }
 
 #[derive(Default)]
 pub struct Generator {
     names: NameGenerator,
     out: Transient,
+    /// Whether element constructors clone a template instead of building new nodes
+    template: bool,
 }
 
 impl Generator {
+    fn generate(mut self, mut nodes: Vec<Node>) -> Transient {
+        let dom_node = if nodes.len() == 1 {
+            nodes.remove(0).into_gen(&mut self)
+        } else {
+            nodes.into_gen(&mut self)
+        };
+
+        self.hoist(dom_node);
+        self.out
+    }
+
     fn add_field(&mut self, value: TokenStream) -> &mut Field {
         let name = self.names.next();
 
@@ -58,6 +76,19 @@ impl Generator {
         self.out.fields.last_mut().unwrap()
     }
 
+    /// Add an attribute field, `{ static ... }` attributes are set once and not stored in the product.
+    fn add_attr(&mut self, expr: Expression, el: Short, attr: Attr, prop: TokenStream) -> Short {
+        let field = self.add_field(expr.stream);
+
+        if expr.is_static {
+            field.static_attr(el, attr, prop);
+        } else {
+            field.attr(el, attr, prop);
+        }
+
+        field.name
+    }
+
     fn add_hint(&mut self, name: Ident, typ: impl Tokenize) {
         self.out.hints.push(Hint {
             name,
@@ -73,8 +104,14 @@ impl Generator {
     }
 
     fn hoist(&mut self, node: DomNode) -> Option<JsFnName> {
+        if let DomNode::Variable(_) = node {
+            return None;
+        }
+
+        let name = crate::unique();
+
         let (var, body, args, anchor) = match node {
-            DomNode::Variable(_) => return None,
+            DomNode::Variable(_) => unreachable!(),
             DomNode::TextNode(text) => {
                 let body = format!("return document.createTextNode({text});\n");
                 let var = self.names.next_el();
@@ -86,15 +123,18 @@ impl Generator {
                 typ,
                 var,
                 code,
+                setup,
                 args,
                 hoisted: _,
             }) => {
                 let create_tag = tag.to_js_create_element();
 
-                let body = if code.is_empty() {
+                let body = if self.template {
+                    template_body(name, var, &create_tag, &code, &setup, &args)
+                } else if code.is_empty() && setup.is_empty() {
                     format!("return {create_tag};\n")
                 } else {
-                    format!("let {var}={create_tag};\n{code}return {var};\n")
+                    format!("let {var}={create_tag};\n{code}{setup}return {var};\n")
                 };
 
                 (var, body, args, Anchor::Element(typ))
@@ -111,8 +151,6 @@ impl Generator {
 
         self.out.els.push(var);
 
-        let name = crate::unique();
-
         let js_args = args.iter().map(|a| a.name).join(",");
 
         let _ = write!(
@@ -132,6 +170,45 @@ impl Generator {
     }
 }
 
+/// Body of an element constructor that builds the element once, with comment placeholders
+/// in place of the DOM nodes passed in as arguments, and on every call clones it and swaps
+/// the placeholders for the arguments. Paths to the placeholders are found once, and all
+/// nodes are looked up before any is replaced, as arguments can be fragments.
+///
+/// The template is cached as a property of the constructor function, temporaries are prefixed
+/// with `$` so they never collide with argument names.
+fn template_body(
+    name: JsFnName,
+    var: Short,
+    create_tag: &str,
+    code: &str,
+    setup: &str,
+    args: &[JsArgument],
+) -> String {
+    let nodes = args
+        .iter()
+        .filter(|a| a.abi.is_none())
+        .map(|a| a.name)
+        .join(",")
+        .to_string();
+
+    format!(
+        "let $t={name}.$t;\n\
+        if(!$t){{\
+            let $p=[{nodes}].map(()=>document.createComment(\"\"));\
+            let $r=(({nodes})=>{{let {var}={create_tag};\n{code}return {var};}})(...$p);\
+            $t={name}.$t=[$r,$p.map($n=>{{\
+                let $i=[];\
+                for(;$n!==$r;$n=$n.parentNode)$i.unshift([].indexOf.call($n.parentNode.childNodes,$n));\
+                return $i;\
+            }})];\
+        }}\n\
+        let {var}=$t[0].cloneNode(true);\n\
+        $t[1].map($i=>$i.reduce(($n,$i)=>$n.childNodes[$i],{var})).forEach(($n,$i)=>$n.replaceWith([{nodes}][$i]));\n\
+        {setup}return {var};\n"
+    )
+}
+
 trait IntoGenerator {
     fn into_gen(self, gen: &mut Generator) -> DomNode;
 }
@@ -144,7 +221,11 @@ impl IntoGenerator for Expression {
             field.kind = FieldKind::StaticView;
         }
 
-        DomNode::Variable(field.name)
+        let name = field.name;
+
+        gen.out.html.view(name);
+
+        DomNode::Variable(name)
     }
 }
 
@@ -154,7 +235,11 @@ impl IntoGenerator for Node {
             Node::Component(component) => component.into_gen(gen),
             Node::HtmlElement(element) => element.into_gen(gen),
             Node::Expression(expr) => expr.into_gen(gen),
-            Node::Text(lit) => DomNode::TextNode(JsString(lit)),
+            Node::Text(lit) => {
+                gen.out.html.text(lit.clone());
+
+                DomNode::TextNode(JsString(lit))
+            }
         }
     }
 }
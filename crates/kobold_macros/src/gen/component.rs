@@ -18,9 +18,10 @@ impl Component {
             render.write(("::", generics));
         }
 
-        let mut params = self.path;
-
-        params.write("::props()");
+        let mut params = match self.spread {
+            Some(spread) => group('(', spread).tokenize(),
+            None => (self.path, "::props()").tokenize(),
+        };
 
         for Property { name, expr } in self.props {
             params.write(('.', call(name, expr.stream)));
@@ -42,6 +43,7 @@ impl IntoGenerator for Component {
         let value = self.into_expression();
 
         gen.out.fields.push(Field::new(name, value));
+        gen.out.html.view(name);
 
         DomNode::Variable(name)
     }
@@ -9,7 +9,7 @@ use tokens::{Literal, TokenStream};
 use crate::dom::{Attribute, AttributeValue, CssValue, ElementTag, HtmlElement};
 use crate::gen::{append, DomNode, Generator, IntoGenerator, JsArgument, Short};
 use crate::itertools::IteratorExt as _;
-use crate::parse::IteratorExt as _;
+use crate::parse::{IdentExt, IteratorExt as _};
 use crate::tokenize::prelude::*;
 
 pub struct JsElement {
@@ -25,6 +25,10 @@ pub struct JsElement {
     /// Method calls on constructed element, such as `e0.append(foo);` or `e0.className = bar;`
     pub code: String,
 
+    /// Method calls using arguments with an ABI type, such as `e0.value = foo;`. These are
+    /// only kept apart from `code` for templates, as they need to run for every clone.
+    pub setup: String,
+
     /// Arguments to import from rust, with optional ABI type
     pub args: Vec<JsArgument>,
 
@@ -36,10 +40,17 @@ impl JsElement {
     fn write_fmt(&mut self, args: Arguments) {
         let _ = self.code.write_fmt(args);
     }
+
+    fn write_setup(&mut self, template: bool, args: Arguments) {
+        let _ = match template {
+            true => self.setup.write_fmt(args),
+            false => self.code.write_fmt(args),
+        };
+    }
 }
 
 impl IntoGenerator for HtmlElement {
-    fn into_gen(mut self, gen: &mut Generator) -> DomNode {
+    fn into_gen(self, gen: &mut Generator) -> DomNode {
         let var = gen.names.next_el();
         let typ = element_js_type(&self.name);
 
@@ -48,61 +59,105 @@ impl IntoGenerator for HtmlElement {
             typ,
             var,
             code: String::new(),
+            setup: String::new(),
             args: Vec::new(),
             hoisted: false,
         };
 
-        match (self.classes.len(), el.tag.namespace().is_none()) {
-            (0, _) => (),
-            (1, true) => match self.classes.remove(0) {
-                CssValue::Literal(class) => writeln!(el, "{var}.className={class};"),
-                CssValue::Expression(expr) => {
-                    el.hoisted = true;
+        gen.out.html.push_str("<");
+        gen.out.html.push_str(&el.tag);
 
-                    let attr = Attr {
-                        name: "ClassName",
-                        abi: None,
-                    };
-                    gen.add_field(expr.stream).attr(el.var, attr, attr.prop());
+        // All literal classes are merged into a single static prefix
+        let mut literals = String::new();
+        let mut dynamic = Vec::new();
+
+        for class in self.classes {
+            match class {
+                CssValue::Literal(lit) => {
+                    let lit = lit.to_string();
+
+                    if !literals.is_empty() {
+                        literals.push(' ');
+                    }
+                    literals.push_str(lit.trim_matches('"'));
                 }
-            },
-            _ => {
-                let lit_count = self.classes.iter().map(CssValue::is_literal).count();
+                CssValue::Expression(expr) => dynamic.push(expr),
+            }
+        }
+
+        let is_html = el.tag.namespace().is_none();
 
-                if lit_count > 0 {
-                    let classes = self
-                        .classes
-                        .iter()
-                        .filter_map(CssValue::as_literal)
-                        .join(",");
+        if literals.is_empty() && dynamic.len() == 1 && is_html {
+            el.hoisted = true;
+
+            let attr = Attr {
+                name: "ClassName",
+                abi: None,
+            };
+            let value = gen.add_attr(dynamic.remove(0), el.var, attr, attr.prop());
+
+            gen.out.html.attr(value, attr.prop());
+        } else if !literals.is_empty() || !dynamic.is_empty() {
+            if !literals.is_empty() {
+                if is_html {
+                    writeln!(el, "{var}.className={};", Literal::string(&literals));
+                } else {
+                    let classes = literals.split(' ').map(Literal::string).join(",");
 
                     writeln!(el, "{var}.classList.add({classes});");
                 }
+            }
+
+            gen.out.html.push_str(" class=\"");
+
+            if !literals.is_empty() {
+                gen.out.html.text(Literal::string(&literals));
+            }
 
-                let attr = Attr {
-                    name: "Class",
-                    abi: None,
+            for expr in dynamic {
+                el.hoisted = true;
+
+                // Static classes are added once and never need to be replaced
+                let attr = match expr.is_static {
+                    true => Attr::new("AddClass"),
+                    false => Attr {
+                        name: "Class",
+                        abi: None,
+                    },
                 };
+                let value = gen.add_attr(expr, el.var, attr, attr.prop());
 
-                for class in self.classes {
-                    if let CssValue::Expression(expr) = class {
-                        el.hoisted = true;
-                        gen.add_field(expr.stream).attr(el.var, attr, attr.prop());
-                    }
-                }
+                gen.out.html.attr(value, attr.prop());
             }
+
+            gen.out.html.push_str("\"");
         }
 
+        // Content set by the `html` attribute, rendered after the opening tag
+        let mut inner_html = None;
+
         for Attribute { name, value } in self.attributes {
-            let attr_type = attribute_type(&name.label);
+            let (label, modifiers) = match name.label.split_once('.') {
+                Some((label, modifiers)) => (label, Some(modifiers)),
+                None => (name.label.as_str(), None),
+            };
+            let attr_type = attribute_type(label);
 
             match value {
                 AttributeValue::Literal(value) => {
                     let name = attribute_name(&name.label);
                     writeln!(el, "{var}.setAttribute(\"{name}\",{value});");
+
+                    gen.out.html.push_str(&format!(" {name}=\""));
+                    gen.out.html.text(value);
+                    gen.out.html.push_str("\"");
                 }
                 AttributeValue::Boolean(value) => {
                     writeln!(el, "{var}.{name}={value};");
+
+                    if value.eq_str("true") {
+                        gen.out.html.push_str(&format!(" {name}"));
+                    }
                 }
                 AttributeValue::Expression(mut expr) => match &attr_type {
                     AttributeType::Event(event) => {
@@ -126,10 +181,22 @@ impl IntoGenerator for HtmlElement {
 
                         let value = gen.add_field(coerce).event(event, el.typ).name;
 
-                        writeln!(
-                            el,
-                            "{var}.addEventListener(\"{}\",{value});",
-                            &name.label[2..]
+                        let options = match modifiers {
+                            Some(modifiers) => format!(
+                                ",{{{}}}",
+                                modifiers.split('.').map(|m| format!("{m}:true")).join(",")
+                            ),
+                            None => String::new(),
+                        };
+
+                        // Listeners aren't cloned, elements with them can't be part of a template
+                        el.hoisted |= gen.template;
+                        el.write_setup(
+                            gen.template,
+                            format_args!(
+                                "{var}.addEventListener(\"{}\",{value}{options});\n",
+                                &label[2..]
+                            ),
                         );
 
                         el.args.push(JsArgument::with_abi(value, InlineAbi::Event))
@@ -138,13 +205,16 @@ impl IntoGenerator for HtmlElement {
                         let name = attribute_name(&name.label);
                         el.hoisted = true;
 
-                        let value = gen
-                            .add_field(expr.stream)
-                            .attr(var, *attr, attr.prop())
-                            .name;
+                        let value = gen.add_attr(expr, var, *attr, attr.prop());
+
+                        if attr.name == "InnerHtml" {
+                            inner_html = Some(value);
+                        } else {
+                            gen.out.html.attr(value, attr.prop());
+                        }
 
                         if let Some(abi) = attr.abi {
-                            writeln!(el, "{var}.{name}={value};");
+                            el.write_setup(gen.template, format_args!("{var}.{name}={value};\n"));
                             el.args.push(JsArgument::with_abi(value, abi))
                         }
                     }
@@ -154,7 +224,9 @@ impl IntoGenerator for HtmlElement {
                         let prop = (Literal::string(&name.label), ".into()").tokenize();
                         let attr = Attr::new("&AttributeName");
 
-                        gen.add_field(expr.stream).attr(var, attr, prop);
+                        let value = gen.add_attr(expr, var, attr, prop.clone());
+
+                        gen.out.html.attr(value, prop);
                     }
                 },
             };
@@ -183,11 +255,23 @@ impl IntoGenerator for HtmlElement {
             }
         }
 
+        gen.out.html.push_str(">");
+
+        if let Some(value) = inner_html {
+            gen.out.html.attr(value, Attr::new("InnerHtml").prop());
+        }
+
         if let Some(children) = self.children {
             let append = append(gen, &mut el.code, &mut el.args, children);
             writeln!(el, "{var}.{append};");
         }
 
+        if !el.tag.forbids_children() {
+            gen.out.html.push_str("</");
+            gen.out.html.push_str(&el.tag);
+            gen.out.html.push_str(">");
+        }
+
         DomNode::Element(el)
     }
 }
@@ -280,6 +364,10 @@ fn attribute_type(attr: &str) -> AttributeType {
     }
 
     let attr = match attr {
+        "attrs" => Attr {
+            name: "Attributes",
+            abi: None,
+        },
         "checked" => Attr {
             name: "Checked",
             abi: Some(InlineAbi::Bool),
@@ -310,6 +398,7 @@ fn attribute_type(attr: &str) -> AttributeType {
 fn event_js_type(event: &str) -> &'static str {
     match event {
         "click"
+        | "contextmenu"
         | "dblclick"
         | "mousedown"
         | "mouseup"
@@ -322,6 +411,19 @@ fn event_js_type(event: &str) -> &'static str {
         "keydown"
         | "keyup"
         | "keypress" => "KeyboardEvent",
+
+        "wheel" => "WheelEvent",
+
+        "scroll" => "UiEvent",
+
+        "drag"
+        | "dragend"
+        | "dragenter"
+        | "dragleave"
+        | "dragover"
+        | "dragstart"
+        | "drop" => "DragEvent",
+
         _ => "Event",
     }
 }
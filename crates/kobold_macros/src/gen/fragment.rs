@@ -68,6 +68,7 @@ pub fn append(
                     let _ = writeln!(js, "let {}={create_tag};", el.var);
 
                     js.push_str(&el.code);
+                    js.push_str(&el.setup);
 
                     args.extend(el.args);
                 }
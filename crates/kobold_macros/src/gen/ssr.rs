@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! HTML output of the `view!` macro used by the `ssr` feature.
+
+use std::fmt::Write;
+
+use tokens::{Literal, TokenStream};
+
+use crate::gen::Short;
+
+#[derive(Debug)]
+enum Segment {
+    /// Static HTML, written verbatim
+    Static(String),
+    /// Literal text that needs to be escaped
+    Text(Literal),
+    /// Field implementing `View`
+    View(Short),
+    /// Field implementing `Attribute`, with its property
+    Attribute { name: Short, prop: TokenStream },
+    /// Scoped stylesheet, written once per render
+    Style { id: String, css: Literal },
+}
+
+/// Sequence of static HTML and fields in the order they appear in the DOM.
+#[derive(Default, Debug)]
+pub struct Html {
+    segments: Vec<Segment>,
+}
+
+impl Html {
+    pub fn push_str(&mut self, html: &str) {
+        match self.segments.last_mut() {
+            Some(Segment::Static(last)) => last.push_str(html),
+            _ => self.segments.push(Segment::Static(html.into())),
+        }
+    }
+
+    pub fn text(&mut self, text: Literal) {
+        self.segments.push(Segment::Text(text));
+    }
+
+    pub fn view(&mut self, name: Short) {
+        self.segments.push(Segment::View(name));
+    }
+
+    pub fn attr(&mut self, name: Short, prop: TokenStream) {
+        self.segments.push(Segment::Attribute { name, prop });
+    }
+
+    pub fn style(&mut self, id: String, css: Literal) {
+        self.segments.push(Segment::Style { id, css });
+    }
+
+    /// Write the body of the `View::render_html` method to `buf`
+    pub fn render(&self, buf: &mut String) {
+        for segment in self.segments.iter() {
+            let _ = match segment {
+                Segment::Static(html) => write!(buf, "out.push_str({});", Literal::string(html)),
+                Segment::Text(text) => write!(buf, "::kobold::ssr::escape({text}, out);"),
+                Segment::View(name) => {
+                    write!(buf, "::kobold::View::render_html(self.{name}, out);")
+                }
+                Segment::Attribute { name, prop } => write!(
+                    buf,
+                    "::kobold::attribute::Attribute::render_html(self.{name}, {prop}, out);"
+                ),
+                Segment::Style { id, css } => {
+                    write!(buf, "::kobold::ssr::style({id:?}, {css}, out);")
+                }
+            };
+        }
+    }
+}
@@ -8,7 +8,7 @@ use arrayvec::ArrayString;
 use tokens::{Ident, Literal, TokenStream};
 
 use crate::gen::element::{Attr, InlineAbi};
-use crate::gen::Short;
+use crate::gen::{Html, Short};
 use crate::itertools::IteratorExt;
 use crate::parse::IdentExt;
 use crate::tokenize::prelude::*;
@@ -22,6 +22,7 @@ pub struct Transient {
     pub hints: Vec<Hint>,
     pub fields: Vec<Field>,
     pub els: Vec<Short>,
+    pub html: Html,
 }
 
 #[derive(Debug)]
@@ -47,14 +48,33 @@ impl Transient {
     fn tokenize_const(self, stream: &mut TokenStream) {
         let JsFunction { name, .. } = self.js.functions[0];
 
+        #[cfg(not(feature = "ssr"))]
+        let precompiled = format_args!("::kobold::internal::Precompiled({name})");
+
+        #[cfg(feature = "ssr")]
+        let render_html = self.render_html("|out: &mut String|");
+        #[cfg(feature = "ssr")]
+        let precompiled = format_args!("::kobold::internal::Precompiled({name}, {render_html})");
+
         block((
             "use ::kobold::reexport::wasm_bindgen;",
             self.js,
-            format_args!("::kobold::internal::Precompiled({name})"),
+            precompiled,
         ))
         .tokenize_in(stream)
     }
 
+    /// Body of the `View::render_html` method, prefixed with `signature`
+    #[cfg(feature = "ssr")]
+    fn render_html(&self, signature: &str) -> String {
+        let mut render_html = format!("{signature} {{");
+
+        self.html.render(&mut render_html);
+
+        render_html.push('}');
+        render_html
+    }
+
     fn transient_signature(&self) -> TokenStream {
         let mut generics = String::new();
         let mut declare = String::new();
@@ -117,6 +137,11 @@ impl Tokenize for Transient {
             return self.fields.remove(0).value.tokenize_in(stream);
         }
 
+        #[cfg(not(feature = "ssr"))]
+        let render_html = "";
+        #[cfg(feature = "ssr")]
+        let render_html = self.render_html("fn render_html(self, out: &mut String)");
+
         let mut generics = String::new();
 
         let mut build = String::new();
@@ -138,7 +163,7 @@ impl Tokenize for Transient {
             field.declare(&mut declare);
 
             match field.kind {
-                FieldKind::StaticView => (),
+                FieldKind::StaticView | FieldKind::StaticAttribute { .. } => (),
                 _ => {
                     let _ = write!(product_generics, "{typ},");
                     let _ = write!(product_generics_binds, "{typ}::Product,");
@@ -229,6 +254,8 @@ impl Tokenize for Transient {
                     fn update(self, p: &mut Self::Product) {{\
                         {update}\
                     }}\
+                    \
+                    {render_html}\
                 }}\
                 \
                 "
@@ -391,6 +418,11 @@ pub enum FieldKind {
         attr: Attr,
         prop: TokenStream,
     },
+    StaticAttribute {
+        el: Short,
+        attr: Attr,
+        prop: TokenStream,
+    },
 }
 
 impl Debug for Field {
@@ -410,6 +442,9 @@ impl Debug for Field {
             FieldKind::Attribute { attr, .. } => {
                 write!(f, "{name} <Attribute<{}>>: {value}", attr.name)
             }
+            FieldKind::StaticAttribute { attr, .. } => {
+                write!(f, "{name} <StaticAttribute<{}>>: {value}", attr.name)
+            }
         }
     }
 }
@@ -433,6 +468,11 @@ impl Field {
         self
     }
 
+    pub fn static_attr(&mut self, el: Short, attr: Attr, prop: TokenStream) -> &mut Self {
+        self.kind = FieldKind::StaticAttribute { el, attr, prop };
+        self
+    }
+
     fn name_value(&self) -> (&Short, &TokenStream) {
         (&self.name, &self.value)
     }
@@ -464,7 +504,7 @@ impl Field {
                     >,"
                 ));
             }
-            FieldKind::Attribute { attr, .. } => {
+            FieldKind::Attribute { attr, .. } | FieldKind::StaticAttribute { attr, .. } => {
                 let (amp, attr_name) = attr.as_parts();
                 buf.write((
                     format_args!(
@@ -518,6 +558,10 @@ impl Field {
                     "::kobold::init!(_p.{name} = self.{name}.build_in({prop}, &{el}));"
                 );
             }
+            FieldKind::StaticAttribute { attr, .. } if attr.abi.is_some() => (),
+            FieldKind::StaticAttribute { el, prop, .. } => {
+                let _ = write!(post, "self.{name}.build_in({prop}, &{el});");
+            }
         }
     }
 
@@ -525,7 +569,7 @@ impl Field {
         let Field { name, kind, .. } = self;
 
         match kind {
-            FieldKind::StaticView => (),
+            FieldKind::StaticView | FieldKind::StaticAttribute { .. } => (),
             FieldKind::View | FieldKind::Event { .. } => {
                 let _ = write!(buf, "self.{name}.update(&mut p.{name});");
             }
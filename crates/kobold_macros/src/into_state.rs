@@ -0,0 +1,61 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use tokens::{Ident, TokenStream};
+
+use crate::parse::prelude::*;
+use crate::tokenize::prelude::*;
+
+pub fn derive(stream: TokenStream) -> Result<TokenStream, ParseError> {
+    let mut stream = stream.parse_stream();
+
+    // Skip attributes and visibility
+    while stream.allow_consume('#').is_some() {
+        stream.expect('[')?;
+    }
+
+    if stream.allow_consume("pub").is_some() {
+        stream.allow_consume('(');
+    }
+
+    if stream.allow_consume("struct").is_none() && stream.allow_consume("enum").is_none() {
+        return Err(ParseError::new(
+            "IntoState can only be derived for structs and enums",
+            stream.next(),
+        ));
+    }
+
+    let name: Ident = stream.parse()?;
+
+    if let Some(tt) = stream.allow_consume('<') {
+        return Err(ParseError::new(
+            "IntoState can't be derived for generic types, use a closure instead",
+            tt,
+        ));
+    }
+
+    Ok((
+        "impl ::kobold::stateful::IntoState for",
+        name,
+        block(
+            "type State = Self;\
+            fn init(self) -> Self { self }\
+            fn update(self, _: &mut Self) -> ::kobold::stateful::Then {\
+                ::kobold::stateful::Then::Stop\
+            }",
+        ),
+    )
+        .tokenize())
+}
+
+pub fn item_error(err: ParseError) -> TokenStream {
+    (call("compile_error!", string(err.msg.as_ref())), ';')
+        .tokenize()
+        .into_iter()
+        .map(|mut tt| {
+            tt.set_span(err.span);
+            tt
+        })
+        .collect()
+}
@@ -17,9 +17,11 @@ use arrayvec::ArrayString;
 
 mod branching;
 mod class;
+mod diff;
 mod dom;
 mod fn_component;
 mod gen;
+mod into_state;
 mod itertools;
 mod parse;
 mod syntax;
@@ -51,13 +53,30 @@ pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
 #[allow(clippy::let_and_return)]
 #[proc_macro]
 pub fn view(body: TokenStream) -> TokenStream {
-    let nodes = unwrap_err!(dom::parse(body.into()));
+    let (template, body) = unwrap_err!(dom::take_template(body.into()));
+    let mut nodes = unwrap_err!(dom::parse(body));
+    let key = unwrap_err!(dom::take_key(&mut nodes));
+    let style = unwrap_err!(dom::take_style(&mut nodes));
 
     // panic!("{nodes:#?}");
 
-    let transient = gen::generate(nodes);
+    if template {
+        unwrap_err!(dom::check_template(&nodes));
+    }
 
-    let out = transient.tokenize();
+    let mut transient = match template {
+        true => gen::generate_template(nodes),
+        false => gen::generate(nodes),
+    };
+
+    if let Some(style) = style {
+        style.inject(&mut transient);
+    }
+
+    let out = match key {
+        Some(key) => call("::kobold::list::keyed::keyed", (key, ',', transient)),
+        None => transient.tokenize(),
+    };
 
     // panic!("{out}");
 
@@ -72,6 +91,31 @@ pub fn class(stream: TokenStream) -> TokenStream {
     out.into()
 }
 
+#[proc_macro_derive(Diff)]
+pub fn derive_diff(input: TokenStream) -> TokenStream {
+    derive(input, diff::derive)
+}
+
+#[proc_macro_derive(IntoState)]
+pub fn derive_into_state(input: TokenStream) -> TokenStream {
+    derive(input, into_state::derive)
+}
+
+type Derive = fn(tokens::TokenStream) -> Result<tokens::TokenStream, parse::ParseError>;
+
+#[cfg(not(test))]
+fn derive(input: TokenStream, derive: Derive) -> TokenStream {
+    // Derive output is in item position, so the error can't be wrapped in a block
+    derive(input).unwrap_or_else(into_state::item_error)
+}
+
+#[cfg(test)]
+fn derive(input: TokenStream, derive: Derive) -> TokenStream {
+    derive(input.into())
+        .unwrap_or_else(into_state::item_error)
+        .into()
+}
+
 fn unique() -> ArrayString<8> {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
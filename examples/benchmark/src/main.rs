@@ -0,0 +1,189 @@
+use kobold::prelude::*;
+use web_sys::Node;
+
+/// Number of times each benchmark is run, results are averaged.
+const RUNS: u32 = 10;
+
+/// Average time in milliseconds it takes to run `bench` in a fresh container attached
+/// to the document, including the layout of the rendered nodes.
+fn measure<R>(bench: impl Fn(&Node) -> R) -> f64 {
+    let window = web_sys::window().expect("no window");
+    let document = window.document().expect("no document");
+    let performance = window.performance().expect("no performance");
+    let body = document.body().expect("no body");
+
+    let mut total = 0.0;
+
+    for _ in 0..RUNS {
+        let container = document.create_element("div").unwrap();
+        let _ = body.append_child(&container);
+
+        let start = performance.now();
+        // Keep the result around until the time is taken, mounted views are
+        // removed from the DOM when dropped.
+        let result = bench(&container);
+        let _ = container.client_height();
+
+        total += performance.now() - start;
+
+        drop(result);
+        container.remove();
+    }
+
+    total / RUNS as f64
+}
+
+/// Average time in milliseconds it takes to update a mounted view with `view(run)`.
+fn measure_updates<V: View>(view: impl Fn(u32) -> V) -> f64 {
+    let window = web_sys::window().expect("no window");
+    let document = window.document().expect("no document");
+    let performance = window.performance().expect("no performance");
+    let body = document.body().expect("no body");
+
+    let container = document.create_element("div").unwrap();
+    let _ = body.append_child(&container);
+
+    let mut mount = kobold::mount(&container, view(0));
+
+    let start = performance.now();
+
+    for run in 1..=RUNS {
+        mount.update(view(run));
+        let _ = container.client_height();
+    }
+
+    let total = performance.now() - start;
+
+    drop(mount);
+    container.remove();
+
+    total / RUNS as f64
+}
+
+#[component]
+fn rows(count: usize) -> impl View {
+    view! {
+        <ul>{ for (0..count).map(|n| view! { <li>"Row #"{ n }</li> }) }</ul>
+    }
+}
+
+/// Value rendered in the first row, which is the only one changing between updates.
+fn cell(row: usize, run: u32) -> u32 {
+    match row {
+        0 => run,
+        _ => 0,
+    }
+}
+
+#[component]
+fn table(run: u32) -> impl View {
+    view! {
+        <table>
+        {
+            for (0..1000).map(move |row| view! {
+                <tr><td>{ row }</td><td>"Row #"{ row }</td><td>{ cell(row, run) }</td></tr>
+            })
+        }
+        </table>
+    }
+}
+
+#[component]
+fn memo_table(run: u32) -> impl View {
+    let rows = (0..1000).map(move |row| {
+        (
+            cell(row, run),
+            view! {
+                <tr><td>{ row }</td><td>"Row #"{ row }</td><td>{ cell(row, run) }</td></tr>
+            },
+        )
+    });
+
+    view! {
+        <table>{ for memo rows }</table>
+    }
+}
+
+#[component]
+fn template_cell(x: usize, y: usize) -> impl View {
+    view! {
+        #[template]
+        <td.cell data-x={x}><b>{ x }", "{ y }</b><i>"static"</i></td>
+    }
+}
+
+#[component]
+fn plain_cell(x: usize, y: usize) -> impl View {
+    view! {
+        <td.cell data-x={x}><b>{ x }", "{ y }</b><i>"static"</i></td>
+    }
+}
+
+#[component]
+fn grid(size: usize, template: bool) -> impl View + 'static {
+    view! {
+        <table>
+        {
+            for (0..size).map(move |y| view! {
+                <tr>
+                {
+                    for (0..size).map(move |x| match template {
+                        true => Branch2::A(view! { <!template_cell {x} {y}> }),
+                        false => Branch2::B(view! { <!plain_cell {x} {y}> }),
+                    })
+                }
+                </tr>
+            })
+        }
+        </table>
+    }
+}
+
+fn main() {
+    // Cache the template before measuring
+    measure(|node| kobold::mount(node, view! { <!grid size={1} template={true}> }));
+
+    let results = [
+        (
+            "5000 rows, initial render",
+            measure(|node| kobold::mount(node, view! { <!rows count={5000}> })),
+        ),
+        (
+            "5000 rows, growing from empty",
+            measure(|node| {
+                let mut mount = kobold::mount(node, view! { <!rows count={0}> });
+
+                mount.update(view! { <!rows count={5000}> });
+                mount
+            }),
+        ),
+        (
+            "1000 rows, updating one row with `for`",
+            measure_updates(|run| view! { <!table {run}> }),
+        ),
+        (
+            "1000 rows, updating one row with `for memo`",
+            measure_updates(|run| view! { <!memo_table {run}> }),
+        ),
+        (
+            "50x50 grid, built",
+            measure(|node| kobold::mount(node, view! { <!grid size={50} template={false}> })),
+        ),
+        (
+            "50x50 grid, cloned with `#[template]`",
+            measure(|node| kobold::mount(node, view! { <!grid size={50} template={true}> })),
+        ),
+    ];
+
+    kobold::start(view! {
+        <h1>"Benchmarks"</h1>
+        <p>"Average of "{ RUNS }" runs"</p>
+        <table>
+        {
+            for results.into_iter().map(|(name, ms)| view! {
+                <tr><td>{ name }</td><td>{ format!("{ms:.2} ms") }</td></tr>
+            })
+        }
+        </table>
+    });
+}
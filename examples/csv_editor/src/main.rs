@@ -10,7 +10,7 @@ use state::{Editing, State, Text};
 fn editor() -> impl View {
     stateful(State::mock, |state| {
         let onload = state.bind_async(|state, event: Event<InputElement>| async move {
-            let file = match event.current_target().files().and_then(|list| list.get(0)) {
+            let file = match event.current_target().file() {
                 Some(file) => file,
                 None => return,
             };
@@ -1,13 +1,10 @@
 use kobold::prelude::*;
-use kobold::reexport::web_sys::HtmlTextAreaElement;
 use kobold_qr::qr;
 
 #[component]
 fn qr_example() -> impl View {
     stateful("Enter something", |data| {
-        let onkeyup = event!(|data, e: KeyboardEvent<HtmlTextAreaElement>| {
-            *data = e.current_target().value();
-        });
+        let onkeyup = data.bind_input(|data, value, _| *data = value);
 
         view! {
             <h1>"QR code example"</h1>
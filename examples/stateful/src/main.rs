@@ -18,12 +18,11 @@ impl State {
 fn app(state: &Hook<State>) -> impl View + '_ {
     // Repeatedly clicking the Alice button does not have to do anything.
     let alice = event!(|state| {
-        if state.name != "Alice" {
-            "Alice".clone_into(&mut state.name);
-            Then::Render
-        } else {
-            Then::Stop
-        }
+        let changed = state.name != "Alice";
+
+        "Alice".clone_into(&mut state.name);
+
+        Then::render_if(changed)
     });
 
     view! {